@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    // Character that has no meaning in the puzzle.
+    InvalidTile,
+    InvalidNumber,
+    // Text does not have the expected shape, holds what was expected instead.
+    Malformed(&'static str),
+    // Input is well formed but the puzzle has no answer for it.
+    NoSolution(&'static str),
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::InvalidTile => write!(f, "invalid tile"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::Malformed(expected) => write!(f, "expected {}", expected),
+            ErrorKind::NoSolution(reason) => write!(f, "no solution, {}", reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub kind: ErrorKind,
+    // Offending text, empty when the input as a whole is at fault.
+    pub text: String,
+    pub day: Option<u32>,
+    // Both 1 based.
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl Error {
+    pub fn new(kind: ErrorKind, text: impl Into<String>) -> Self {
+        Error {
+            kind,
+            text: text.into(),
+            day: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn invalid_tile(tile: char) -> Self {
+        Error::new(ErrorKind::InvalidTile, tile)
+    }
+
+    // Errors without any offending text, e.g. a missing start tile.
+    pub fn no_solution(reason: &'static str) -> Self {
+        Error::new(ErrorKind::NoSolution(reason), "")
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Self {
+        Error {
+            line: Some(line),
+            column: Some(column),
+            ..self
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> Self {
+        Error {
+            line: Some(line),
+            ..self
+        }
+    }
+
+    pub fn in_day(self, day: u32) -> Self {
+        Error {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut location = Vec::new();
+        if let Some(day) = self.day {
+            location.push(format!("day {}", day));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {}", line));
+        }
+        if let Some(column) = self.column {
+            location.push(format!("column {}", column));
+        }
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+        write!(f, "{}", self.kind)?;
+        if !self.text.is_empty() {
+            write!(f, ", found `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,105 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::iter::Peekable;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::vec;
+
+// When set, inputs are read from `<dir>/dayNN.txt` instead of each crate's input.txt.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    // "-" means stdin, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+
+    // Input file for given day: inside input_dir if one is given, otherwise default_path.
+    pub fn for_day(day: u32, input_dir: Option<&Path>, default_path: &str) -> Self {
+        match input_dir {
+            Some(dir) => InputSource::Path(dir.join(format!("day{:02}.txt", day))),
+            None => InputSource::Path(PathBuf::from(default_path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::Stdin => {
+                let mut input_str = String::new();
+                io::stdin()
+                    .read_to_string(&mut input_str)
+                    .map_err(|err| format!("Cannot read input from stdin: {}", err))?;
+                Ok(input_str)
+            }
+            InputSource::Path(path) => fs::read_to_string(path)
+                .map_err(|err| format!("Cannot read input file {}: {}", path.display(), err)),
+        }
+    }
+}
+
+pub fn input_dir_from_env() -> Option<PathBuf> {
+    env::var_os(INPUT_DIR_ENV).map(PathBuf::from)
+}
+
+// Input for the per day binaries: `dayNN [PATH|-]`.
+pub fn read_input_from_args(day: u32, default_path: &str) -> Result<String, String> {
+    let source = match env::args().nth(1) {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::for_day(day, input_dir_from_env().as_deref(), default_path),
+    };
+    source.read()
+}
+
+// Arguments after a flag, the flag takes its values from the front.
+pub type FlagArgs = Peekable<vec::IntoIter<String>>;
+
+// Input for the per day binaries that take flags: `dayNN [PATH|-] [FLAGS]`. Every argument
+// starting with `--` goes to parse_flag, which fails with the usage for flags it does not know.
+pub fn input_source_from_args(
+    day: u32,
+    default_path: &str,
+    usage: &str,
+    parse_flag: impl FnMut(&str, &mut FlagArgs) -> Result<(), String>,
+) -> Result<InputSource, String> {
+    let args = env::args().skip(1).collect();
+    parse_input_args(args, day, default_path, usage, parse_flag)
+}
+
+pub(crate) fn parse_input_args(
+    args: Vec<String>,
+    day: u32,
+    default_path: &str,
+    usage: &str,
+    mut parse_flag: impl FnMut(&str, &mut FlagArgs) -> Result<(), String>,
+) -> Result<InputSource, String> {
+    let mut source = None;
+    let mut it = args.into_iter().peekable();
+    while let Some(arg) = it.next() {
+        if arg.starts_with("--") {
+            parse_flag(&arg, &mut it)?;
+        } else if source.is_none() {
+            source = Some(InputSource::from_arg(&arg));
+        } else {
+            return Err(usage.to_string());
+        }
+    }
+    Ok(source.unwrap_or_else(|| {
+        InputSource::for_day(day, input_dir_from_env().as_deref(), default_path)
+    }))
+}
+
+pub fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+mod error;
+mod input;
+mod parse;
+mod solution;
+
+pub use error::{Error, ErrorKind};
+pub use input::{
+    exit_with_error, input_dir_from_env, input_source_from_args, read_input_from_args, FlagArgs,
+    InputSource, INPUT_DIR_ENV,
+};
+pub use parse::{numbered_lines, parse_grid, parse_grid_lines, InputLine};
+pub use solution::{run, solve_part_one, solve_part_two, Solution};
+
+pub type Grid<T> = Vec<Vec<T>>;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy, Ord, PartialOrd)]
+pub struct Location {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+
+    // (x, y) change of a single step, y grows downwards.
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val = match &self {
+            Direction::Up => "^",
+            Direction::Down => "v",
+            Direction::Left => "<",
+            Direction::Right => ">",
+        };
+        write!(f, "{}", val)
+    }
+}
+
+pub fn is_inbound<T>(grid: &Grid<T>, x: i32, y: i32) -> bool {
+    0 <= y && (y as usize) < grid.len() && 0 <= x && (x as usize) < grid[y as usize].len()
+}
+
+pub fn get_neighbour<T>(
+    grid: &Grid<T>,
+    location: &Location,
+    direction: Direction,
+) -> Option<Location> {
+    let (dx, dy) = direction.offset();
+    let x = location.x as i32 + dx;
+    let y = location.y as i32 + dy;
+    if is_inbound(grid, x, y) {
+        Some(Location {
+            x: x as usize,
+            y: y as usize,
+        })
+    } else {
+        None
+    }
+}
+
+// Every edge cell with the direction pointing into the grid: the top row going down, the bottom
+// row going up, then the left and right columns. Corners show up once for each of their edges.
+// A grid without cells has no rays.
+pub fn boundary_rays<T>(grid: &Grid<T>) -> impl Iterator<Item = (Location, Direction)> {
+    let (width, height) = match grid.first().map_or(0, |row| row.len()) {
+        0 => (0, 0),
+        width => (width, grid.len()),
+    };
+    let top = (0..width).map(|x| (Location { x, y: 0 }, Direction::Down));
+    let bottom = (0..width).map(move |x| (Location { x, y: height - 1 }, Direction::Up));
+    let left = (0..height).map(|y| (Location { x: 0, y }, Direction::Right));
+    let right = (0..height).map(move |y| (Location { x: width - 1, y }, Direction::Left));
+    top.chain(bottom).chain(left).chain(right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_respect_grid_bounds() {
+        let grid: Grid<u8> = vec![vec![0; 5]; 2];
+        let corner = Location { x: 4, y: 0 };
+        assert_eq!(get_neighbour(&grid, &corner, Direction::Right), None);
+        assert_eq!(get_neighbour(&grid, &corner, Direction::Up), None);
+        assert_eq!(
+            get_neighbour(&grid, &corner, Direction::Down),
+            Some(Location { x: 4, y: 1 })
+        );
+        assert_eq!(
+            get_neighbour(&grid, &corner, Direction::Left),
+            Some(Location { x: 3, y: 0 })
+        );
+        assert!(!is_inbound(&grid, 2, 2));
+        assert!(!is_inbound(&grid, -1, 0));
+
+        let rays: Vec<_> = boundary_rays(&grid).collect();
+        assert_eq!(rays.len(), 14);
+        assert!(rays.contains(&(Location { x: 2, y: 1 }, Direction::Up)));
+        assert!(rays.contains(&(Location { x: 4, y: 1 }, Direction::Left)));
+        assert!(!rays.contains(&(Location { x: 1, y: 4 }, Direction::Left)));
+        let empty: Grid<u8> = vec![vec![]; 3];
+        assert_eq!(boundary_rays(&empty).count(), 0);
+    }
+
+    #[test]
+    fn input_source_selection() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::for_day(7, Some(std::path::Path::new("inputs")), "day07/input.txt"),
+            InputSource::Path("inputs/day07.txt".into())
+        );
+        assert_eq!(
+            InputSource::for_day(7, None, "day07/input.txt"),
+            InputSource::Path("day07/input.txt".into())
+        );
+        let missing = InputSource::from_arg("does/not/exist.txt").read();
+        assert!(missing.unwrap_err().contains("does/not/exist.txt"));
+
+        let args = ["--count", "3", "-", "--quiet"].map(String::from).to_vec();
+        let mut flags = Vec::new();
+        let source = input::parse_input_args(args, 7, "day07/input.txt", "usage", |flag, args| {
+            match flag {
+                "--count" => flags.push(args.next().ok_or("usage")?),
+                "--quiet" => flags.push(flag.to_string()),
+                _ => return Err("usage".to_string()),
+            }
+            Ok(())
+        });
+        assert_eq!(source, Ok(InputSource::Stdin));
+        assert_eq!(flags, ["3", "--quiet"]);
+        let args = ["a.txt", "b.txt"].map(String::from).to_vec();
+        let source = input::parse_input_args(args, 7, "day07/input.txt", "usage", |_, _| Ok(()));
+        assert_eq!(source, Err("usage".to_string()));
+    }
+
+    #[derive(Debug)]
+    struct Bit(bool);
+
+    impl TryFrom<char> for Bit {
+        type Error = Error;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '0' => Ok(Bit(false)),
+                '1' => Ok(Bit(true)),
+                _ => Err(Error::invalid_tile(value)),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors_point_at_offending_text() {
+        let grid = parse_grid::<Bit>("\n01\n10\n").unwrap();
+        assert!(grid[0][1].0 && grid[1][0].0);
+        let err = parse_grid::<Bit>("\n010\n01x\n").unwrap_err();
+        assert_eq!(
+            (err.kind, err.line, err.column),
+            (ErrorKind::InvalidTile, Some(3), Some(3))
+        );
+        assert_eq!(
+            err.in_day(4).to_string(),
+            "day 4, line 3, column 3: invalid tile, found `x`"
+        );
+        let err = parse_grid::<Bit>("010\n01\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            ErrorKind::Malformed("all rows to have the same length")
+        );
+        assert_eq!(err.line, Some(2));
+
+        let line = numbered_lines("\nmove 12 to x3").next().unwrap();
+        assert_eq!(line.parse::<u32>(&line.text[5..7]), Ok(12));
+        let err = line.parse::<u32>(&line.text[11..]).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(12)));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 12: invalid number, found `x3`"
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use crate::error::{Error, ErrorKind};
+use crate::Grid;
+
+// Line of the puzzle input that knows where it came from, so errors raised
+// while parsing it point at the offending line and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    // 1 based
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    // 1 based column of part, None if part is not a slice of this line.
+    fn column_of(&self, part: &str) -> Option<usize> {
+        let offset = (part.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)?;
+        if offset + part.len() > self.text.len() {
+            return None;
+        }
+        Some(self.text[..offset].chars().count() + 1)
+    }
+
+    // Error about part of this line, part should be a slice of the line text.
+    pub fn error(&self, kind: ErrorKind, part: &str) -> Error {
+        let error = Error::new(kind, part);
+        match self.column_of(part) {
+            Some(column) => error.at(self.number, column),
+            None => error.at_line(self.number),
+        }
+    }
+
+    // Error about the line as a whole.
+    pub fn malformed(&self, expected: &'static str) -> Error {
+        Error::new(ErrorKind::Malformed(expected), self.text).at_line(self.number)
+    }
+
+    pub fn parse<T: FromStr>(&self, part: &str) -> Result<T, Error> {
+        part.parse()
+            .map_err(|_| self.error(ErrorKind::InvalidNumber, part))
+    }
+
+    // Whitespace separated numbers.
+    pub fn parse_numbers<T: FromStr>(&self, part: &'a str) -> Result<Vec<T>, Error> {
+        part.split_whitespace().map(|n| self.parse(n)).collect()
+    }
+}
+
+// Non empty lines of the input.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| InputLine {
+            number: idx + 1,
+            text: text.trim_end(),
+        })
+        .filter(|line| !line.text.is_empty())
+}
+
+// Rectangular grid with one tile per character.
+pub fn parse_grid_lines<'a, T>(
+    lines: impl IntoIterator<Item = InputLine<'a>>,
+) -> Result<Grid<T>, Error>
+where
+    T: TryFrom<char, Error = Error>,
+{
+    let mut grid: Grid<T> = Vec::new();
+    for line in lines {
+        let row = line
+            .text
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| T::try_from(c).map_err(|err| err.at(line.number, idx + 1)))
+            .collect::<Result<Vec<T>, Error>>()?;
+        if grid.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(line.malformed("all rows to have the same length"));
+        }
+        grid.push(row);
+    }
+    if grid.is_empty() {
+        return Err(Error::new(ErrorKind::Malformed("at least one row"), ""));
+    }
+    Ok(grid)
+}
+
+pub fn parse_grid<T>(input_str: &str) -> Result<Grid<T>, Error>
+where
+    T: TryFrom<char, Error = Error>,
+{
+    parse_grid_lines(numbered_lines(input_str))
+}
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+
+use crate::error::Error;
+use crate::input::{exit_with_error, read_input_from_args};
+
+pub trait Solution {
+    const DAY: u32;
+
+    type Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error>;
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error>;
+
+    // Days that only have the first part solved keep this default.
+    fn part_two(_input: &Self::Input) -> Result<impl Display, Error> {
+        Ok("Not solved yet")
+    }
+}
+
+pub fn solve_part_one<S: Solution>(input_str: &str) -> Result<String, Error> {
+    let input = S::parse(input_str).map_err(|err| err.in_day(S::DAY))?;
+    let answer = S::part_one(&input).map_err(|err| err.in_day(S::DAY))?;
+    Ok(answer.to_string())
+}
+
+pub fn solve_part_two<S: Solution>(input_str: &str) -> Result<String, Error> {
+    let input = S::parse(input_str).map_err(|err| err.in_day(S::DAY))?;
+    let answer = S::part_two(&input).map_err(|err| err.in_day(S::DAY))?;
+    Ok(answer.to_string())
+}
+
+// Entry point for the per day binaries, prints both parts.
+pub fn run<S: Solution>(name: &str, default_path: &str) {
+    let input_str =
+        read_input_from_args(S::DAY, default_path).unwrap_or_else(|err| exit_with_error(&err));
+    let fail = |err: Error| -> ! { exit_with_error(&err.in_day(S::DAY).to_string()) };
+    let input = S::parse(&input_str).unwrap_or_else(|err| fail(err));
+    let part_one = S::part_one(&input).unwrap_or_else(|err| fail(err));
+    println!("Result for {} part one: {}", name, part_one);
+    let part_two = S::part_two(&input).unwrap_or_else(|err| fail(err));
+    println!("Result for {} part two: {}", name, part_two);
+}
@@ -0,0 +1,187 @@
+use std::fmt::Display;
+
+// Checked in answers, one per line: `<day> <part> <input hash> <right|wrong> <answer>`.
+pub const MANIFEST_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    // Submitted once and rejected, kept so the same mistake is caught again.
+    Wrong,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u32,
+    pub part: char,
+    pub input_hash: String,
+    pub verdict: Verdict,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Correct,
+    KnownWrong,
+    Mismatch(String),
+    // No right answer recorded for this input.
+    Unknown,
+}
+
+impl Check {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Check::KnownWrong | Check::Mismatch(_))
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Correct => write!(f, "ok"),
+            Check::KnownWrong => write!(f, "known wrong"),
+            Check::Mismatch(expected) => write!(f, "expected {}", expected),
+            Check::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+// FNV-1a over the input lines, so line endings and trailing blank lines do not matter.
+pub fn input_hash(input_str: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for (idx, line) in input_str.trim_end().lines().enumerate() {
+        let separator: &[u8] = if idx == 0 { b"" } else { b"\n" };
+        for byte in separator.iter().chain(line.trim_end().as_bytes()) {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+pub fn parse_manifest(manifest_str: &str) -> Result<Vec<Answer>, String> {
+    let mut answers = Vec::new();
+    for (idx, line) in manifest_str.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("Invalid answer on line {}: {}", idx + 1, line);
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        let [day, part, input_hash, verdict, value] = cols[..] else {
+            return Err(invalid());
+        };
+        let verdict = match verdict {
+            "right" => Verdict::Right,
+            "wrong" => Verdict::Wrong,
+            _ => return Err(invalid()),
+        };
+        let part = match part {
+            "a" => 'a',
+            "b" => 'b',
+            _ => return Err(invalid()),
+        };
+        answers.push(Answer {
+            day: day.parse().map_err(|_| invalid())?,
+            part,
+            input_hash: input_hash.to_string(),
+            verdict,
+            value: value.to_string(),
+        });
+    }
+    Ok(answers)
+}
+
+pub fn read_manifest() -> Result<Vec<Answer>, String> {
+    let manifest_str = std::fs::read_to_string(MANIFEST_PATH)
+        .map_err(|err| format!("Cannot read answers {}: {}", MANIFEST_PATH, err))?;
+    parse_manifest(&manifest_str)
+}
+
+pub fn check(answers: &[Answer], day: u32, part: char, input_hash: &str, value: &str) -> Check {
+    let recorded = answers
+        .iter()
+        .filter(|a| a.day == day && a.part == part && a.input_hash == input_hash);
+    let mut expected = None;
+    for answer in recorded {
+        match answer.verdict {
+            Verdict::Wrong if answer.value == value => return Check::KnownWrong,
+            Verdict::Right if answer.value == value => return Check::Correct,
+            Verdict::Right => expected = Some(answer.value.clone()),
+            Verdict::Wrong => {}
+        }
+    }
+    match expected {
+        Some(expected) => Check::Mismatch(expected),
+        None => Check::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SOLVERS;
+
+    #[test]
+    fn checks_answers_against_manifest() {
+        let hash = input_hash("1abc2\r\npqr3stu8vwx\n\n");
+        assert_eq!(hash, input_hash("1abc2\npqr3stu8vwx"));
+        let manifest = format!(
+            "# day part hash verdict answer\n17 a {0} wrong 1176\n17 a {0} right 1155\n",
+            hash
+        );
+        let answers = parse_manifest(&manifest).unwrap();
+        assert_eq!(check(&answers, 17, 'a', &hash, "1155"), Check::Correct);
+        assert_eq!(check(&answers, 17, 'a', &hash, "1176"), Check::KnownWrong);
+        assert_eq!(
+            check(&answers, 17, 'a', &hash, "1191"),
+            Check::Mismatch("1155".to_string())
+        );
+        assert_eq!(check(&answers, 17, 'b', &hash, "1"), Check::Unknown);
+        assert!(parse_manifest("17 c 0 right 1").is_err());
+    }
+
+    // Runs every solver that has a right answer recorded against its real input, and checks
+    // every recorded answer has a solver.
+    #[test]
+    fn solvers_match_manifest() {
+        let answers = read_manifest().unwrap();
+        let mut failures = Vec::new();
+        for solver in SOLVERS {
+            let input_str = std::fs::read_to_string(solver.default_input).unwrap();
+            let hash = input_hash(&input_str);
+            let recorded = |verdict| {
+                answers.iter().any(|a| {
+                    (a.day, a.part, a.input_hash.as_str(), a.verdict)
+                        == (solver.day, solver.part, hash.as_str(), verdict)
+                })
+            };
+            if !recorded(Verdict::Right) {
+                if !recorded(Verdict::Wrong) {
+                    failures.push(format!(
+                        "day {} {}: not in answers",
+                        solver.day, solver.part
+                    ));
+                }
+                continue;
+            }
+            let result = (solver.solve)(&input_str)
+                .map(|value| check(&answers, solver.day, solver.part, &hash, &value));
+            match result {
+                Ok(Check::Correct) => {}
+                Ok(other) => {
+                    failures.push(format!("day {} {}: {}", solver.day, solver.part, other))
+                }
+                Err(err) => failures.push(err.to_string()),
+            }
+        }
+        for answer in &answers {
+            let registered = SOLVERS
+                .iter()
+                .any(|solver| (solver.day, solver.part) == (answer.day, answer.part));
+            if !registered {
+                failures.push(format!("day {} {}: no solver", answer.day, answer.part));
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
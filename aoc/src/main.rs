@@ -0,0 +1,235 @@
+use aoc_core::{input_dir_from_env, InputSource};
+use std::env;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+mod answers;
+mod registry;
+
+use answers::{input_hash, read_manifest, Check};
+use registry::{Solver, SOLVERS};
+
+const USAGE: &str =
+    "Usage: aoc run <all|DAY|FROM..TO|FROM..=TO> [a|b] [--input PATH|-] [--inputs DIR] [--check]";
+
+#[derive(Debug)]
+struct Options {
+    days: RangeInclusive<u32>,
+    part: Option<char>,
+    // Same input for every selected solver, "-" reads it from stdin.
+    input: Option<InputSource>,
+    // Directory holding dayNN.txt files.
+    input_dir: Option<PathBuf>,
+    // Fail when an answer does not match the answers manifest.
+    check: bool,
+}
+
+fn parse_days(arg: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse_day = |s: &str| {
+        s.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid day: {}", s))
+    };
+    if arg == "all" {
+        Ok(1..=25)
+    } else if let Some((from, to)) = arg.split_once("..=") {
+        Ok(parse_day(from)?..=parse_day(to)?)
+    } else if let Some((from, to)) = arg.split_once("..") {
+        let to = parse_day(to)?;
+        if to == 0 {
+            return Err(format!("Invalid day range: {}", arg));
+        }
+        Ok(parse_day(from)?..=to - 1)
+    } else {
+        let day = parse_day(arg)?;
+        Ok(day..=day)
+    }
+}
+
+fn parse_part(arg: &str) -> Result<char, String> {
+    match arg {
+        "a" => Ok('a'),
+        "b" => Ok('b'),
+        other => Err(format!("Invalid part: {}", other)),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let (days, rest) = match args {
+        [command, days, rest @ ..] if command == "run" => (parse_days(days)?, rest),
+        _ => return Err(USAGE.to_string()),
+    };
+    let mut options = Options {
+        days,
+        part: None,
+        input: None,
+        input_dir: None,
+        check: false,
+    };
+    let mut it = rest.iter();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--input" => {
+                let value = it.next().ok_or("--input needs a path or -")?;
+                options.input = Some(InputSource::from_arg(value));
+            }
+            "--inputs" => {
+                let value = it.next().ok_or("--inputs needs a directory")?;
+                options.input_dir = Some(PathBuf::from(value));
+            }
+            "--check" => options.check = true,
+            part if options.part.is_none() => options.part = Some(parse_part(part)?),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(options)
+}
+
+fn select(days: &RangeInclusive<u32>, part: Option<char>) -> Vec<&'static Solver> {
+    SOLVERS
+        .iter()
+        .filter(|solver| days.contains(&solver.day))
+        .filter(|solver| part.is_none_or(|p| p == solver.part))
+        .collect()
+}
+
+// Answer and its check, or why the solver or its input failed.
+type Outcome = Result<(String, Check), String>;
+
+fn print_table(rows: &[(&Solver, Outcome, Duration)]) {
+    let headers = ["Day", "Part", "Answer", "Check", "Time"];
+    let cells: Vec<[String; 5]> = rows
+        .iter()
+        .map(|(solver, outcome, elapsed)| {
+            let (answer, check) = match outcome {
+                Ok((answer, check)) => (answer.clone(), check.to_string()),
+                Err(err) => ("-".to_string(), format!("error: {}", err)),
+            };
+            [
+                solver.day.to_string(),
+                solver.part.to_string(),
+                answer,
+                check,
+                format!("{:.2?}", elapsed),
+            ]
+        })
+        .collect();
+    let mut widths = headers.map(|h| h.len());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let format_row = |row: [&str; 5]| {
+        row.iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+    println!("{}", format_row(headers));
+    println!("{}", widths.map(|width| "-".repeat(width)).join("-+-"));
+    for row in &cells {
+        println!("{}", format_row(row.each_ref().map(|cell| cell.as_str())));
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let solvers = select(&options.days, options.part);
+    if solvers.is_empty() {
+        return Err(format!("No solver found for days {:?}", options.days));
+    }
+    // Explicit input is read once so stdin can be shared between parts.
+    let shared_input = match &options.input {
+        Some(source) => Some(source.read()?),
+        None => None,
+    };
+    let input_dir = options.input_dir.or_else(input_dir_from_env);
+    // An installed runner may not have the manifest, then every answer is unknown.
+    let answers = if options.check || Path::new(answers::MANIFEST_PATH).exists() {
+        read_manifest()?
+    } else {
+        Vec::new()
+    };
+    let mut rows = Vec::new();
+    for solver in solvers {
+        let input_str = match &shared_input {
+            Some(input_str) => Ok(input_str.clone()),
+            None => {
+                InputSource::for_day(solver.day, input_dir.as_deref(), solver.default_input).read()
+            }
+        };
+        let start = Instant::now();
+        let outcome = input_str.and_then(|input_str| {
+            let answer = (solver.solve)(&input_str).map_err(|err| err.to_string())?;
+            let hash = input_hash(&input_str);
+            let check = answers::check(&answers, solver.day, solver.part, &hash, &answer);
+            Ok((answer, check))
+        });
+        rows.push((solver, outcome, start.elapsed()));
+    }
+    print_table(&rows);
+    let errors = rows
+        .iter()
+        .filter(|(_, outcome, _)| outcome.is_err())
+        .count();
+    let mismatches = rows
+        .iter()
+        .filter(|(_, outcome, _)| matches!(outcome, Ok((_, check)) if check.is_failure()))
+        .count();
+    if errors > 0 && !options.check {
+        return Err(format!("{} of {} solvers failed", errors, rows.len()));
+    }
+    if options.check && errors + mismatches > 0 {
+        return Err(format!(
+            "{} answers failed or do not match {}",
+            errors + mismatches,
+            answers::MANIFEST_PATH
+        ));
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_day_selections() {
+        assert_eq!(parse_days("all"), Ok(1..=25));
+        assert_eq!(parse_days("10"), Ok(10..=10));
+        assert_eq!(parse_days("5..=9"), Ok(5..=9));
+        assert_eq!(parse_days("5..9"), Ok(5..=8));
+        assert!(parse_days("ten").is_err());
+        let solvers = select(&parse_days("10").unwrap(), Some('b'));
+        assert_eq!(solvers.len(), 1);
+        assert_eq!((solvers[0].day, solvers[0].part), (10, 'b'));
+    }
+
+    #[test]
+    fn parses_input_options() {
+        let args: Vec<String> = ["run", "5", "--input", "-", "b", "--check"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let options = parse_options(&args).unwrap();
+        assert_eq!(options.days, 5..=5);
+        assert_eq!(options.part, Some('b'));
+        assert_eq!(options.input, Some(InputSource::Stdin));
+        assert_eq!(options.input_dir, None);
+        assert!(options.check);
+    }
+}
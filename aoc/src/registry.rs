@@ -0,0 +1,216 @@
+use aoc_core::{solve_part_one, solve_part_two, Error};
+
+pub struct Solver {
+    pub day: u32,
+    pub part: char,
+    pub solve: fn(&str) -> Result<String, Error>,
+    // input.txt next to the day's crate, used when no other input is given.
+    pub default_input: &'static str,
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver {
+        day: 1,
+        part: 'a',
+        solve: solve_part_one::<day01::Day01>,
+        default_input: day01::INPUT_PATH,
+    },
+    Solver {
+        day: 1,
+        part: 'b',
+        solve: solve_part_two::<day01::Day01>,
+        default_input: day01::INPUT_PATH,
+    },
+    Solver {
+        day: 2,
+        part: 'a',
+        solve: solve_part_one::<day02::Day02>,
+        default_input: day02::INPUT_PATH,
+    },
+    Solver {
+        day: 2,
+        part: 'b',
+        solve: solve_part_two::<day02::Day02>,
+        default_input: day02::INPUT_PATH,
+    },
+    Solver {
+        day: 3,
+        part: 'a',
+        solve: solve_part_one::<day03::Day03>,
+        default_input: day03::INPUT_PATH,
+    },
+    Solver {
+        day: 3,
+        part: 'b',
+        solve: solve_part_two::<day03::Day03>,
+        default_input: day03::INPUT_PATH,
+    },
+    Solver {
+        day: 4,
+        part: 'a',
+        solve: solve_part_one::<day04::Day04>,
+        default_input: day04::INPUT_PATH,
+    },
+    Solver {
+        day: 4,
+        part: 'b',
+        solve: solve_part_two::<day04::Day04>,
+        default_input: day04::INPUT_PATH,
+    },
+    Solver {
+        day: 5,
+        part: 'a',
+        solve: solve_part_one::<day05::Day05>,
+        default_input: day05::INPUT_PATH,
+    },
+    Solver {
+        day: 5,
+        part: 'b',
+        solve: solve_part_two::<day05::Day05>,
+        default_input: day05::INPUT_PATH,
+    },
+    Solver {
+        day: 6,
+        part: 'a',
+        solve: solve_part_one::<day06::Day06>,
+        default_input: day06::INPUT_PATH,
+    },
+    Solver {
+        day: 6,
+        part: 'b',
+        solve: solve_part_two::<day06::Day06>,
+        default_input: day06::INPUT_PATH,
+    },
+    Solver {
+        day: 7,
+        part: 'a',
+        solve: solve_part_one::<day07::Day07>,
+        default_input: day07::INPUT_PATH,
+    },
+    Solver {
+        day: 7,
+        part: 'b',
+        solve: solve_part_two::<day07::Day07>,
+        default_input: day07::INPUT_PATH,
+    },
+    Solver {
+        day: 8,
+        part: 'a',
+        solve: solve_part_one::<day08::Day08>,
+        default_input: day08::INPUT_PATH,
+    },
+    Solver {
+        day: 8,
+        part: 'b',
+        solve: solve_part_two::<day08::Day08>,
+        default_input: day08::INPUT_PATH,
+    },
+    Solver {
+        day: 9,
+        part: 'a',
+        solve: solve_part_one::<day09::Day09>,
+        default_input: day09::INPUT_PATH,
+    },
+    Solver {
+        day: 9,
+        part: 'b',
+        solve: solve_part_two::<day09::Day09>,
+        default_input: day09::INPUT_PATH,
+    },
+    Solver {
+        day: 10,
+        part: 'a',
+        solve: solve_part_one::<day10::Day10>,
+        default_input: day10::INPUT_PATH,
+    },
+    Solver {
+        day: 10,
+        part: 'b',
+        solve: solve_part_two::<day10::Day10>,
+        default_input: day10::INPUT_PATH,
+    },
+    Solver {
+        day: 11,
+        part: 'a',
+        solve: solve_part_one::<day11::Day11>,
+        default_input: day11::INPUT_PATH,
+    },
+    Solver {
+        day: 11,
+        part: 'b',
+        solve: solve_part_two::<day11::Day11>,
+        default_input: day11::INPUT_PATH,
+    },
+    Solver {
+        day: 12,
+        part: 'a',
+        solve: solve_part_one::<day12::Day12>,
+        default_input: day12::INPUT_PATH,
+    },
+    Solver {
+        day: 12,
+        part: 'b',
+        solve: solve_part_two::<day12::Day12>,
+        default_input: day12::INPUT_PATH,
+    },
+    Solver {
+        day: 13,
+        part: 'a',
+        solve: solve_part_one::<day13::Day13>,
+        default_input: day13::INPUT_PATH,
+    },
+    Solver {
+        day: 13,
+        part: 'b',
+        solve: solve_part_two::<day13::Day13>,
+        default_input: day13::INPUT_PATH,
+    },
+    Solver {
+        day: 14,
+        part: 'a',
+        solve: solve_part_one::<day14::Day14>,
+        default_input: day14::INPUT_PATH,
+    },
+    Solver {
+        day: 14,
+        part: 'b',
+        solve: solve_part_two::<day14::Day14>,
+        default_input: day14::INPUT_PATH,
+    },
+    Solver {
+        day: 15,
+        part: 'a',
+        solve: solve_part_one::<day15::Day15>,
+        default_input: day15::INPUT_PATH,
+    },
+    Solver {
+        day: 15,
+        part: 'b',
+        solve: solve_part_two::<day15::Day15>,
+        default_input: day15::INPUT_PATH,
+    },
+    Solver {
+        day: 16,
+        part: 'a',
+        solve: solve_part_one::<day16::Day16>,
+        default_input: day16::INPUT_PATH,
+    },
+    Solver {
+        day: 16,
+        part: 'b',
+        solve: solve_part_two::<day16::Day16>,
+        default_input: day16::INPUT_PATH,
+    },
+    Solver {
+        day: 17,
+        part: 'a',
+        solve: solve_part_one::<day17::Day17>,
+        default_input: day17::INPUT_PATH,
+    },
+    Solver {
+        day: 17,
+        part: 'b',
+        solve: solve_part_two::<day17::Day17>,
+        default_input: day17::INPUT_PATH,
+    },
+];
@@ -0,0 +1,147 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, Solution};
+use std::fmt::Display;
+
+const SPELLED_DIGITS: [(&str, &str); 9] = [
+    ("one", "1"),
+    ("two", "2"),
+    ("three", "3"),
+    ("four", "4"),
+    ("five", "5"),
+    ("six", "6"),
+    ("seven", "7"),
+    ("eight", "8"),
+    ("nine", "9"),
+];
+
+pub struct Line {
+    // 1 based line in the input
+    number: usize,
+    text: String,
+}
+
+impl Line {
+    fn missing_digit(&self) -> Error {
+        Error::new(ErrorKind::Malformed("at least one digit"), self.text.as_str())
+            .at_line(self.number)
+    }
+}
+
+fn parse_line(line: &Line) -> Result<i32, Error> {
+    let chars: Vec<char> = line.text.chars().filter(|c| c.is_ascii_digit()).collect();
+    match (chars.first(), chars.last()) {
+        (Some(first), Some(last)) => Ok(format!("{}{}", first, last).parse().unwrap()),
+        _ => Err(line.missing_digit()),
+    }
+}
+
+fn parse_spelled_line(line: &Line) -> Result<i32, Error> {
+    match (get_first_digit(&line.text), get_last_digit(&line.text)) {
+        (Some(first), Some(last)) => Ok(format!("{}{}", first, last).parse().unwrap()),
+        _ => Err(line.missing_digit()),
+    }
+}
+
+fn get_first_digit(line: &str) -> Option<&str> {
+    for (idx, c) in line.char_indices() {
+        let rest = &line[idx..];
+        if c.is_ascii_digit() {
+            return Some(&rest[0..1]);
+        }
+        for (spelled, num) in &SPELLED_DIGITS {
+            if rest.starts_with(spelled) {
+                return Some(num);
+            }
+        }
+    }
+    None
+}
+
+fn get_last_digit(line: &str) -> Option<&str> {
+    for (idx, c) in line.char_indices().rev() {
+        let rest = &line[..idx + c.len_utf8()];
+        if c.is_ascii_digit() {
+            return Some(&line[idx..idx + 1]);
+        }
+        for (spelled, num) in &SPELLED_DIGITS {
+            if rest.ends_with(spelled) {
+                return Some(num);
+            }
+        }
+    }
+    None
+}
+
+fn calculate_part_one(input: &[Line]) -> Result<i32, Error> {
+    input.iter().map(parse_line).sum()
+}
+
+fn calculate_part_two(input: &[Line]) -> Result<i32, Error> {
+    input.iter().map(parse_spelled_line).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<Line>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Ok(numbered_lines(input_str)
+            .map(|line| Line {
+                number: line.number,
+                text: line.text.trim().to_string(),
+            })
+            .collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day01>("day01", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_works() {
+        let sample_input = "
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+        let result = calculate_part_one(&Day01::parse(sample_input).unwrap());
+        assert_eq!(result, Ok(142));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let sample_input = "
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+
+        let result = calculate_part_two(&Day01::parse(sample_input).unwrap());
+        assert_eq!(result, Ok(281));
+        assert_eq!(calculate_part_two(&Day01::parse("é1x\nsix€").unwrap()), Ok(77));
+
+        let err = calculate_part_two(&Day01::parse("one2\n\nabc").unwrap()).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (Some(3), "abc"));
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day02::run()
+}
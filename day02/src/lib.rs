@@ -0,0 +1,136 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, InputLine, Solution};
+use std::cmp;
+use std::fmt::Display;
+
+#[derive(Debug, Default)]
+struct Turn {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    turns: Vec<Turn>,
+}
+
+fn parse_line(line: InputLine) -> Result<Game, Error> {
+    let expected = "`Game <id>: <count> <color>, ...; ...`";
+    let (title_str, rest_title_str) = line.text.split_once(':').ok_or_else(|| line.malformed(expected))?;
+    let game_id_str = title_str.strip_prefix("Game ").ok_or_else(|| line.malformed(expected))?;
+    let game_id = line.parse(game_id_str.trim())?;
+    let mut turns = Vec::new();
+    for turn_str_raw in rest_title_str.split(';') {
+        let turn_str = turn_str_raw.trim();
+        let mut turn = Turn::default();
+        for color_str_raw in turn_str.split(',') {
+            let color_str = color_str_raw.trim();
+            let (color_count, color_name) = color_str
+                .split_once(' ')
+                .ok_or_else(|| line.error(ErrorKind::Malformed("`<count> <color>`"), color_str))?;
+            let count = line.parse(color_count)?;
+            turn = match color_name {
+                "blue" => Turn { blue: count, ..turn },
+                "green" => Turn { green: count, ..turn },
+                "red" => Turn { red: count, ..turn },
+                _ => return Err(line.error(ErrorKind::Malformed("red, green or blue"), color_name)),
+            }
+        }
+        turns.push(turn);
+    }
+
+    Ok(Game { id: game_id, turns })
+}
+
+fn calculate_possible(game: &Game, check: &Turn) -> i32 {
+    for turn in &game.turns {
+        if turn.green > check.green || turn.red > check.red || turn.blue > check.blue {
+            return 0
+        }
+    }
+    game.id
+}
+
+fn calculate_power(game: &Game) -> i32 {
+    let mut green = 0;
+    let mut red = 0;
+    let mut blue = 0;
+    for turn in &game.turns {
+        green = cmp::max(green, turn.green);
+        red = cmp::max(red, turn.red);
+        blue = cmp::max(blue, turn.blue);
+    }
+    green * red * blue
+}
+
+fn calculate_part_one(games: &[Game], check: Turn) -> i32 {
+    games
+        .iter()
+        .map(|game| calculate_possible(game, &check))
+        .sum()
+}
+
+fn calculate_part_two(games: &[Game]) -> i32 {
+    games.iter().map(calculate_power).sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        numbered_lines(input_str).map(parse_line).collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        let check = Turn{red: 12, green: 13, blue: 14};
+        Ok(calculate_part_one(input, check))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day02>("day02", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn part_one_works() {
+        let check = Turn{red: 12, green: 13, blue: 14};
+        let result = calculate_part_one(&Day02::parse(SAMPLE_INPUT).unwrap(), check);
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Day02::parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 2286);
+    }
+
+    #[test]
+    fn reports_position_of_bad_cube_count() {
+        let err = Day02::parse("Game 1: 3 blue\nGame 2: 4 red, x green").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(16)));
+        assert_eq!(err.to_string(), "line 2, column 16: invalid number, found `x`");
+    }
+}
@@ -0,0 +1,214 @@
+use aoc_core::{is_inbound, numbered_lines, Error, Grid, InputLine, Solution};
+use std::fmt::Display;
+use std::collections::HashSet;
+
+type Board = Grid<char>;
+
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+struct Number {
+    row: usize,
+    start_column: usize,
+    end_column:usize,
+    value: i32,
+}
+
+fn get_char(board: &Board, x: i32, y: i32) -> char {
+    if is_inbound(board, x, y) {
+        board[y as usize][x as usize]
+    } else {
+        '.'
+    }
+}
+
+fn parse_number(board: &Board, x: usize, y: usize) -> Number {
+    let row = &board[y];
+    let mut start = x;
+    let mut end = x;
+    // extend start
+    while start > 0 && row[start-1].is_ascii_digit() {
+        start -= 1;
+    }
+
+    // extend end
+    while end < row.len()-1 && row[end+1].is_ascii_digit() {
+        end += 1;
+    }
+
+    let number_str: String = row[start..=end].iter().collect();
+
+    Number {
+        row:y,
+        start_column:start,
+        end_column:end,
+        value: number_str.parse().expect("numbers are checked while parsing"),
+    }
+    
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+fn is_in_engine(board: &Board, number: &Number) -> bool {
+    let y = number.row;
+    for x in number.start_column..=number.end_column {
+        if is_symbol(get_char(board, x as i32 +1, y as i32  ))
+        || is_symbol(get_char(board, x as i32 +1, y as i32+1))
+        || is_symbol(get_char(board, x as i32 +1, y as i32-1))
+        || is_symbol(get_char(board, x as i32 -1, y as i32  ))
+        || is_symbol(get_char(board, x as i32 -1, y as i32+1))
+        || is_symbol(get_char(board, x as i32 -1, y as i32-1))
+        || is_symbol(get_char(board, x as i32   , y as i32+1))
+        || is_symbol(get_char(board, x as i32   , y as i32-1)) {
+            return true;
+        }
+    }
+    false
+}
+
+fn overflow() -> Error {
+    Error::no_solution("the sum does not fit in 32 bits")
+}
+
+fn find_numbers(board: &Board) -> Result<i32, Error> {
+    let mut result: i32 = 0;
+   let mut seen: HashSet<Number> = HashSet::new();
+   for row_idx in 0..board.len() {
+        let row_len = board[row_idx].len();
+        for col_idx in 0..row_len {
+            if get_char(board,col_idx as i32, row_idx as i32).is_ascii_digit() {
+                let number = parse_number(board, col_idx, row_idx);
+                if !seen.contains(&number) {
+                    seen.insert(number.clone());
+                    if is_in_engine(board, &number) {
+                        result = result.checked_add(number.value).ok_or_else(overflow)?;
+                    }
+                }
+            }
+        }
+   }
+   Ok(result)
+}
+
+// None when the ratio does not fit in an i32.
+fn get_gear_ratio(board: &Board, x: usize, y: usize) -> Option<i32> {
+    let mut numbers: HashSet<Number> = HashSet::new();
+    for y_diff in -1_i32..=1 {
+        for x_diff in -1_i32 ..=1 {
+            if x_diff == 0 && y_diff == 0 {
+                continue;
+            }
+            let num_x = x as i32 +x_diff;
+            let num_y = y as i32 +y_diff;
+            let c = get_char(board,num_x, num_y);
+            if c.is_ascii_digit() {
+                let number = parse_number(board, num_x as usize, num_y as usize); 
+                numbers.insert(number);
+            }
+        }
+    }
+    if numbers.len() > 1 {
+        numbers.iter().try_fold(1_i32, |ratio, number| ratio.checked_mul(number.value))
+    } else {
+        Some(0)
+    }
+}
+
+fn find_gears(board: &Board) -> Result<i32, Error> {
+    let mut result: i32 = 0;
+    // let mut seen: HashSet<Number> = HashSet::new();
+    for row_idx in 0..board.len() {
+        let row_len = board[row_idx].len();
+        for col_idx in 0..row_len {
+            if get_char(board, col_idx as i32, row_idx as i32) == '*' {
+                let ratio = get_gear_ratio(board, col_idx, row_idx).ok_or_else(overflow)?;
+                result = result.checked_add(ratio).ok_or_else(overflow)?;
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn calculate_part_one(board: &Board) -> Result<i32, Error> {
+    find_numbers(board)
+}
+
+fn calculate_part_two(board: &Board) -> Result<i32, Error> {
+    find_gears(board)
+}
+
+// Every run of digits has to fit into an i32.
+fn check_numbers(line: &InputLine) -> Result<(), Error> {
+    for number_str in line.text.split(|c: char| !c.is_ascii_digit()).filter(|s| !s.is_empty()) {
+        line.parse::<i32>(number_str)?;
+    }
+    Ok(())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = Board;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        numbered_lines(input_str)
+            .map(|line| {
+                check_numbers(&line)?;
+                Ok(line.text.chars().collect())
+            })
+            .collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day03>("day03", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Day03::parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(4361));
+
+        let overflowing = Day03::parse("2000000000#2000000000").unwrap();
+        assert_eq!(calculate_part_one(&overflowing), Err(overflow()));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Day03::parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(467835));
+
+        let overflowing = Day03::parse("60000*60000").unwrap();
+        assert_eq!(calculate_part_two(&overflowing), Err(overflow()));
+    }
+}
@@ -0,0 +1,125 @@
+use aoc_core::{numbered_lines, Error, InputLine, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Card {
+    wining_numbers: Vec<i32>,
+    numbers: Vec<i32>,
+}
+
+fn parse_card(line: InputLine) -> Result<Card, Error> {
+    let expected = "`Card <id>: <numbers> | <numbers>`";
+    let (_, game_id_rest) = line
+        .text
+        .split_once(':')
+        .ok_or_else(|| line.malformed(expected))?;
+    let (wining_numbers_str, numbers_str) = game_id_rest
+        .split_once('|')
+        .ok_or_else(|| line.malformed(expected))?;
+    Ok(Card {
+        wining_numbers: line.parse_numbers(wining_numbers_str)?,
+        numbers: line.parse_numbers(numbers_str)?,
+    })
+}
+
+fn count_matches(card: &Card) -> i32 {
+    let wining_numbers: HashSet<i32> = HashSet::from_iter(card.wining_numbers.clone());
+    let mut result = 0;
+    for n in &card.numbers {
+        if wining_numbers.contains(n) {
+            result += 1;
+        }
+    }
+    result
+}
+
+fn get_points(card: &Card) -> i32 {
+    match count_matches(card) {
+        0 => 0,
+        matches => 1 << (matches - 1),
+    }
+}
+
+fn calculate_part_one(cards: &[Card]) -> i32 {
+    cards.iter().map(get_points).sum()
+}
+
+fn calculate_part_two(cards: &[Card]) -> i32 {
+    // We have one card of each
+    let mut card_counts = vec![1; cards.len()];
+    let mut current_idx = 0;
+    let mut current_card_count = card_counts.first().copied().unwrap_or(0);
+    while current_idx < cards.len() && current_card_count > 0 {
+        let current_card = &cards[current_idx];
+        let winning_count = count_matches(current_card);
+        for card_count in card_counts
+            .iter_mut()
+            .skip(current_idx + 1)
+            .take(winning_count as usize)
+        {
+            // Add up one card for each current card we have.
+            *card_count += current_card_count;
+        }
+        current_idx += 1;
+        if cards.len() > current_idx {
+            current_card_count = card_counts[current_idx];
+        } else {
+            current_card_count = 0;
+        }
+    }
+
+    card_counts.into_iter().sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Card>;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        numbered_lines(input_str).map(parse_card).collect()
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day04>("day04", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Day04::parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 13);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Day04::parse(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 30);
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day05::run()
+}
@@ -0,0 +1,867 @@
+use aoc_core::{exit_with_error, read_input_from_args, Error, ErrorKind, InputLine, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Display;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+struct Range {
+    source_start_idx: u64,
+    destination_start_idx: u64,
+    len: u64,
+    // 1 based line in the input, None for ranges that are calculated.
+    line: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct SourceDestinationMap {
+    pub source: String,
+    pub destination: String,
+    ranges: Vec<Range>,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    seeds: Vec<u64>,
+    // Maps keyed by their source category.
+    maps: HashMap<String, Vec<SourceDestinationMap>>,
+}
+
+const RANGE_WITHIN_U64: &str = "ranges that end within 64 bits";
+
+fn read_ranges<'a>(it: &mut impl Iterator<Item = InputLine<'a>>) -> Result<Vec<Range>, Error> {
+    let mut result = Vec::new();
+    for line in it.by_ref() {
+        if line.text.is_empty() {
+            break;
+        }
+        let range_vals: Vec<u64> = line.parse_numbers(line.text)?;
+        if range_vals.len() != 3 {
+            return Err(line.malformed("`<destination start> <source start> <length>`"));
+        }
+        let fits = |start: u64| start.checked_add(range_vals[2]).is_some();
+        if !fits(range_vals[0]) || !fits(range_vals[1]) {
+            return Err(line.malformed(RANGE_WITHIN_U64));
+        }
+        result.push(Range {
+            source_start_idx: range_vals[1],
+            destination_start_idx: range_vals[0],
+            len: range_vals[2],
+            line: Some(line.number),
+        });
+    }
+    Ok(result)
+}
+
+fn parse_header(line: &str) -> Option<(String, String)> {
+    let name = line.strip_suffix(" map:")?;
+    let (source, destination) = name.split_once("-to-")?;
+    Some((source.to_string(), destination.to_string()))
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let mut input_it = input_str.lines().enumerate().map(|(idx, text)| InputLine {
+        number: idx + 1,
+        text: text.trim_end(),
+    });
+    let seeds_expected = "`seeds: <numbers>`";
+    let seeds_line = input_it
+        .next()
+        .ok_or_else(|| Error::new(ErrorKind::Malformed(seeds_expected), ""))?;
+    let seeds_str = seeds_line
+        .text
+        .strip_prefix("seeds:")
+        .ok_or_else(|| seeds_line.malformed(seeds_expected))?;
+    let seeds: Vec<u64> = seeds_line.parse_numbers(seeds_str)?;
+    let mut maps: HashMap<String, Vec<SourceDestinationMap>> = HashMap::new();
+    while let Some(line) = input_it.next() {
+        if line.text.is_empty() {
+            continue;
+        }
+        let (source, destination) = parse_header(line.text)
+            .ok_or_else(|| line.malformed("`<source>-to-<destination> map:`"))?;
+        let ranges = read_ranges(&mut input_it)?;
+        maps.entry(source.clone())
+            .or_default()
+            .push(SourceDestinationMap {
+                source,
+                destination,
+                ranges,
+            });
+    }
+    Ok(Input { seeds, maps })
+}
+
+impl Input {
+    // Shortest chain of maps that leads from source category to destination category.
+    fn find_path<'a>(
+        &'a self,
+        source: &'a str,
+        destination: &str,
+    ) -> Result<Vec<&'a SourceDestinationMap>, Error> {
+        let mut previous: HashMap<&str, &SourceDestinationMap> = HashMap::new();
+        let mut seen: HashSet<&str> = HashSet::from([source]);
+        let mut queue: VecDeque<&str> = VecDeque::from([source]);
+        while let Some(category) = queue.pop_front() {
+            if category == destination {
+                let mut path = Vec::new();
+                let mut current = category;
+                while let Some(map) = previous.get(current) {
+                    path.push(*map);
+                    current = map.source.as_str();
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for map in self.maps.get(category).into_iter().flatten() {
+                if seen.insert(map.destination.as_str()) {
+                    previous.insert(map.destination.as_str(), map);
+                    queue.push_back(map.destination.as_str());
+                }
+            }
+        }
+        Err(Error::new(
+            ErrorKind::NoSolution("no chain of maps between the categories"),
+            format!("{}-to-{}", source, destination),
+        ))
+    }
+
+    // Where value in source category ends up in destination category.
+    pub fn resolve(&self, value: u64, source: &str, destination: &str) -> Result<u64, Error> {
+        let path = self.find_path(source, destination)?;
+        Ok(path.into_iter().fold(value, get_mapping))
+    }
+
+    // Where every value of intervals in source category ends up in destination category.
+    pub fn resolve_intervals(
+        &self,
+        intervals: Vec<Interval>,
+        source: &str,
+        destination: &str,
+    ) -> Result<Vec<Interval>, Error> {
+        let path = self.find_path(source, destination)?;
+        Ok(path.into_iter().fold(intervals, get_interval_mappings))
+    }
+
+    // Single map from source category straight to destination category.
+    pub fn compose_path(
+        &self,
+        source: &str,
+        destination: &str,
+    ) -> Result<SourceDestinationMap, Error> {
+        let path = self.find_path(source, destination)?;
+        let identity = SourceDestinationMap {
+            source: source.to_string(),
+            destination: source.to_string(),
+            ranges: Vec::new(),
+        };
+        Ok(path.into_iter().fold(identity, compose_maps))
+    }
+
+    // Values in source category that end up inside target in destination category.
+    pub fn find_sources(
+        &self,
+        target: Interval,
+        source: &str,
+        destination: &str,
+    ) -> Result<Vec<Interval>, Error> {
+        let composed = self.compose_path(source, destination)?;
+        Ok(get_preimage(target, &composed))
+    }
+}
+
+fn get_mapping(value: u64, current_map: &SourceDestinationMap) -> u64 {
+    for range in &current_map.ranges {
+        if value >= range.source_start_idx && value < (range.source_start_idx + range.len) {
+            let result = (value - range.source_start_idx) + range.destination_start_idx;
+            return result;
+        }
+    }
+    value
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Interval {
+    pub start: u64,
+    // exclusive
+    pub end: u64,
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+const FULL_INTERVAL: Interval = Interval {
+    start: 0,
+    end: u64::MAX,
+};
+
+// Splits interval into pieces that are each moved by a single range. Returned ranges
+// go from the piece in the source category to where it lands in the destination.
+fn get_segments(interval: Interval, current_map: &SourceDestinationMap) -> Vec<Range> {
+    let mut result = Vec::new();
+    let mut unmapped: Vec<Interval> = Some(interval)
+        .filter(|interval| interval.start < interval.end)
+        .into_iter()
+        .collect();
+    for range in &current_map.ranges {
+        let range_end = range.source_start_idx + range.len;
+        let mut next_unmapped = Vec::new();
+        for piece in unmapped {
+            let overlap_start = piece.start.max(range.source_start_idx);
+            let overlap_end = piece.end.min(range_end);
+            if overlap_start >= overlap_end {
+                next_unmapped.push(piece);
+                continue;
+            }
+            result.push(Range {
+                source_start_idx: overlap_start,
+                destination_start_idx: overlap_start - range.source_start_idx
+                    + range.destination_start_idx,
+                len: overlap_end - overlap_start,
+                line: range.line,
+            });
+            if piece.start < overlap_start {
+                next_unmapped.push(Interval {
+                    start: piece.start,
+                    end: overlap_start,
+                });
+            }
+            if overlap_end < piece.end {
+                next_unmapped.push(Interval {
+                    start: overlap_end,
+                    end: piece.end,
+                });
+            }
+        }
+        unmapped = next_unmapped;
+    }
+    // Whatever is not covered by any range maps to itself.
+    result.extend(unmapped.into_iter().map(|piece| Range {
+        source_start_idx: piece.start,
+        destination_start_idx: piece.start,
+        len: piece.end - piece.start,
+        line: None,
+    }));
+    result
+}
+
+fn get_interval_mapping(interval: Interval, current_map: &SourceDestinationMap) -> Vec<Interval> {
+    get_segments(interval, current_map)
+        .into_iter()
+        .map(|segment| Interval {
+            start: segment.destination_start_idx,
+            end: segment.destination_start_idx + segment.len,
+        })
+        .collect()
+}
+
+fn get_interval_mappings(
+    intervals: Vec<Interval>,
+    current_map: &SourceDestinationMap,
+) -> Vec<Interval> {
+    intervals
+        .into_iter()
+        .flat_map(|interval| get_interval_mapping(interval, current_map))
+        .collect()
+}
+
+// Single map that does the same thing as applying first and then second.
+// Ranges of the result cover the whole u64 domain and do not overlap.
+fn compose_maps(
+    first: SourceDestinationMap,
+    second: &SourceDestinationMap,
+) -> SourceDestinationMap {
+    let mut ranges = Vec::new();
+    for first_segment in get_segments(FULL_INTERVAL, &first) {
+        let middle = Interval {
+            start: first_segment.destination_start_idx,
+            end: first_segment.destination_start_idx + first_segment.len,
+        };
+        for second_segment in get_segments(middle, second) {
+            ranges.push(Range {
+                source_start_idx: second_segment.source_start_idx - middle.start
+                    + first_segment.source_start_idx,
+                destination_start_idx: second_segment.destination_start_idx,
+                len: second_segment.len,
+                line: None,
+            });
+        }
+    }
+    ranges.sort_by_key(|range| range.source_start_idx);
+    SourceDestinationMap {
+        source: first.source,
+        destination: second.destination.clone(),
+        ranges,
+    }
+}
+
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by_key(|interval| interval.start);
+    let mut result: Vec<Interval> = Vec::new();
+    for interval in intervals {
+        match result.last_mut() {
+            Some(last) if interval.start <= last.end => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => result.push(interval),
+        }
+    }
+    result
+}
+
+// All source values that current_map sends somewhere into target.
+fn get_preimage(target: Interval, current_map: &SourceDestinationMap) -> Vec<Interval> {
+    let result = get_segments(FULL_INTERVAL, current_map)
+        .into_iter()
+        .filter_map(|segment| {
+            let start = target.start.max(segment.destination_start_idx);
+            let end = target.end.min(segment.destination_start_idx + segment.len);
+            if start >= end {
+                return None;
+            }
+            Some(Interval {
+                start: start - segment.destination_start_idx + segment.source_start_idx,
+                end: end - segment.destination_start_idx + segment.source_start_idx,
+            })
+        })
+        .collect();
+    merge_intervals(result)
+}
+
+fn parse_seeds(raw_seeds: &[u64]) -> Result<Vec<Interval>, Error> {
+    if !raw_seeds.len().is_multiple_of(2) {
+        return Err(Error::new(
+            ErrorKind::Malformed("seeds as pairs of start and length"),
+            format!("{:?}", raw_seeds),
+        ));
+    }
+    // Empty ranges hold no seeds, so they must not count towards the lowest location.
+    raw_seeds
+        .chunks(2)
+        .filter(|pair| pair[1] > 0)
+        .map(|pair| {
+            let end = pair[0].checked_add(pair[1]).ok_or_else(|| {
+                let text = format!("{} {}", pair[0], pair[1]);
+                Error::new(ErrorKind::Malformed(RANGE_WITHIN_U64), text).at_line(1)
+            })?;
+            Ok(Interval {
+                start: pair[0],
+                end,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Diagnostic {
+    // Two ranges claim the same source values, only the first one is ever used.
+    OverlappingSources {
+        line: Option<usize>,
+        other_line: Option<usize>,
+    },
+    // Two ranges send values to the same destination, so the map is not injective.
+    OverlappingDestinations {
+        line: Option<usize>,
+        other_line: Option<usize>,
+    },
+    // Source values below or between ranges that are not covered and map to themselves,
+    // previous_line is None below the lowest range.
+    Gap {
+        start: u64,
+        end: u64,
+        previous_line: Option<usize>,
+        next_line: Option<usize>,
+    },
+    // A range sends values to start..end, where uncovered source values map to themselves, so
+    // the map is not injective either.
+    DestinationInGap {
+        line: Option<usize>,
+        start: u64,
+        end: u64,
+    },
+}
+
+fn describe_line(line: Option<usize>) -> String {
+    match line {
+        Some(line) => format!("line {}", line),
+        None => String::from("a calculated range"),
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Diagnostic::OverlappingSources { line, other_line } => write!(
+                f,
+                "{} and {} map the same source values",
+                describe_line(*line),
+                describe_line(*other_line)
+            ),
+            Diagnostic::OverlappingDestinations { line, other_line } => write!(
+                f,
+                "{} and {} map to the same destination values",
+                describe_line(*line),
+                describe_line(*other_line)
+            ),
+            Diagnostic::Gap {
+                start,
+                end,
+                previous_line: None,
+                next_line,
+            } => write!(
+                f,
+                "source values {}..{} below {} are not mapped",
+                start,
+                end,
+                describe_line(*next_line)
+            ),
+            Diagnostic::Gap {
+                start,
+                end,
+                previous_line,
+                next_line,
+            } => write!(
+                f,
+                "source values {}..{} between {} and {} are not mapped",
+                start,
+                end,
+                describe_line(*previous_line),
+                describe_line(*next_line)
+            ),
+            Diagnostic::DestinationInGap { line, start, end } => write!(
+                f,
+                "{} maps to {}..{}, where unmapped source values map to themselves",
+                describe_line(*line),
+                start,
+                end
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MapDiagnostics {
+    source: String,
+    destination: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn overlaps(start: u64, other_start: u64, len: u64, other_len: u64) -> bool {
+    start < other_start + other_len && other_start < start + len
+}
+
+fn validate_map(current_map: &SourceDestinationMap) -> Vec<Diagnostic> {
+    let mut result = Vec::new();
+    for (idx, range) in current_map.ranges.iter().enumerate() {
+        for other in &current_map.ranges[idx + 1..] {
+            if overlaps(
+                range.source_start_idx,
+                other.source_start_idx,
+                range.len,
+                other.len,
+            ) {
+                result.push(Diagnostic::OverlappingSources {
+                    line: range.line,
+                    other_line: other.line,
+                });
+            }
+            if overlaps(
+                range.destination_start_idx,
+                other.destination_start_idx,
+                range.len,
+                other.len,
+            ) {
+                result.push(Diagnostic::OverlappingDestinations {
+                    line: range.line,
+                    other_line: other.line,
+                });
+            }
+        }
+    }
+
+    let mut sorted: Vec<&Range> = current_map.ranges.iter().filter(|r| r.len > 0).collect();
+    sorted.sort_by_key(|range| range.source_start_idx);
+    // Covered up to the end of the range on previous_line, nothing is covered below the first.
+    let (mut covered_end, mut previous_line) = (0, None);
+    let mut unmapped = Vec::new();
+    for range in &sorted {
+        let range_end = range.source_start_idx + range.len;
+        if covered_end < range.source_start_idx {
+            result.push(Diagnostic::Gap {
+                start: covered_end,
+                end: range.source_start_idx,
+                previous_line,
+                next_line: range.line,
+            });
+            unmapped.push(covered_end..range.source_start_idx);
+        }
+        if covered_end < range_end {
+            (covered_end, previous_line) = (range_end, range.line);
+        }
+    }
+    // Everything above the highest range is not covered either.
+    if !sorted.is_empty() {
+        unmapped.push(covered_end..u64::MAX);
+    }
+
+    for range in current_map.ranges.iter().filter(|r| r.len > 0) {
+        let destination_end = range.destination_start_idx + range.len;
+        for gap in &unmapped {
+            let start = gap.start.max(range.destination_start_idx);
+            let end = gap.end.min(destination_end);
+            if start < end {
+                result.push(Diagnostic::DestinationInGap {
+                    line: range.line,
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+    result
+}
+
+impl Input {
+    fn validate(&self) -> Vec<MapDiagnostics> {
+        let mut result: Vec<MapDiagnostics> = self
+            .maps
+            .values()
+            .flatten()
+            .map(|current_map| MapDiagnostics {
+                source: current_map.source.clone(),
+                destination: current_map.destination.clone(),
+                diagnostics: validate_map(current_map),
+            })
+            .filter(|report| !report.diagnostics.is_empty())
+            .collect();
+        result.sort_by(|a, b| (&a.source, &a.destination).cmp(&(&b.source, &b.destination)));
+        result
+    }
+}
+
+fn calculate_part_one(input: &Input) -> Result<u64, Error> {
+    let locations = input
+        .seeds
+        .iter()
+        .map(|seed| input.resolve(*seed, "seed", "location"))
+        .collect::<Result<Vec<u64>, Error>>()?;
+    locations
+        .into_iter()
+        .min()
+        .ok_or_else(|| Error::no_solution("there are no seeds"))
+}
+
+fn calculate_part_two(input: &Input) -> Result<u64, Error> {
+    let intervals = parse_seeds(&input.seeds)?;
+    input
+        .resolve_intervals(intervals, "seed", "location")?
+        .into_iter()
+        .map(|i| i.start)
+        .min()
+        .ok_or_else(|| Error::no_solution("there are no seeds"))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    let fail = |err: Error| -> ! { exit_with_error(&err.in_day(Day05::DAY).to_string()) };
+    let input_str =
+        read_input_from_args(Day05::DAY, INPUT_PATH).unwrap_or_else(|err| exit_with_error(&err));
+    let input = parse_input(&input_str).unwrap_or_else(|err| fail(err));
+    for report in input.validate() {
+        for diagnostic in report.diagnostics {
+            eprintln!(
+                "Warning for {}-to-{} map: {}",
+                report.source, report.destination, diagnostic
+            );
+        }
+    }
+    let part_one = calculate_part_one(&input).unwrap_or_else(|err| fail(err));
+    println!("Result for day05 part one: {}", part_one);
+    let location = calculate_part_two(&input).unwrap_or_else(|err| fail(err));
+    println!("Result for day05 part two: {}", location);
+    let target = Interval {
+        start: location,
+        end: location + 1,
+    };
+    let seeds = input
+        .find_sources(target, "seed", "location")
+        .unwrap_or_else(|err| fail(err));
+    let seeds: Vec<String> = seeds.iter().map(|interval| interval.to_string()).collect();
+    println!(
+        "Seeds that reach location {}: {}",
+        location,
+        seeds.join(", ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(35));
+
+        let err = parse_input("seeds: 1\n\na-to-b map:\n0 18446744073709551610 10\n").unwrap_err();
+        assert_eq!(
+            (err.kind, err.line),
+            (ErrorKind::Malformed(RANGE_WITHIN_U64), Some(4))
+        );
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(46));
+
+        // The empty range starting at 5 holds no seeds.
+        let input = parse_input("seeds: 5 0 7 3\n\nseed-to-location map:\n1 1 1\n").unwrap();
+        assert_eq!(calculate_part_two(&input), Ok(7));
+        let empty = Interval { start: 5, end: 5 };
+        assert_eq!(
+            input.resolve_intervals(vec![empty], "seed", "location"),
+            Ok(vec![])
+        );
+
+        let input = parse_input("seeds: 18446744073709551610 10\n").unwrap();
+        let err = calculate_part_two(&input).unwrap_err();
+        assert_eq!(
+            (err.kind, err.line),
+            (ErrorKind::Malformed(RANGE_WITHIN_U64), Some(1))
+        );
+    }
+
+    #[test]
+    fn interval_is_split_at_range_boundaries() {
+        let map = SourceDestinationMap {
+            source: String::from("seed"),
+            destination: String::from("soil"),
+            ranges: vec![
+                Range {
+                    source_start_idx: 98,
+                    destination_start_idx: 50,
+                    len: 2,
+                    line: None,
+                },
+                Range {
+                    source_start_idx: 50,
+                    destination_start_idx: 52,
+                    len: 48,
+                    line: None,
+                },
+            ],
+        };
+        let result = get_interval_mapping(
+            Interval {
+                start: 40,
+                end: 110,
+            },
+            &map,
+        );
+        assert_eq!(
+            result,
+            vec![
+                Interval { start: 50, end: 52 },
+                Interval {
+                    start: 52,
+                    end: 100
+                },
+                Interval { start: 40, end: 50 },
+                Interval {
+                    start: 100,
+                    end: 110
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn resolves_between_arbitrary_categories() {
+        let sample_input = "seeds: 79 14 55 13
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+location-to-zone map:
+100 0 50
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+fertilizer-to-location map:
+10 50 10
+";
+        let input = parse_input(sample_input).unwrap();
+        assert_eq!(input.resolve(79, "seed", "fertilizer"), Ok(81));
+        assert_eq!(input.resolve(79, "soil", "zone"), Ok(79));
+        assert_eq!(input.resolve(55, "seed", "zone"), Ok(117));
+        assert_eq!(input.resolve(55, "seed", "seed"), Ok(55));
+        assert!(input.resolve(55, "zone", "seed").is_err());
+        assert!(input.resolve(55, "seed", "water").is_err());
+        let intervals = vec![Interval { start: 55, end: 58 }];
+        assert_eq!(
+            input.resolve_intervals(intervals.clone(), "seed", "zone"),
+            Ok(vec![Interval {
+                start: 117,
+                end: 120
+            }])
+        );
+        assert!(input
+            .resolve_intervals(intervals.clone(), "zone", "seed")
+            .is_err());
+        assert!(input.resolve_intervals(intervals, "seed", "water").is_err());
+    }
+
+    #[test]
+    fn composed_map_matches_chain_and_inverts() {
+        let input = parse_input(SAMPLE_INPUT).unwrap();
+        let composed = input.compose_path("seed", "location").unwrap();
+        for seed in 0..120 {
+            let seed_interval = Interval {
+                start: seed,
+                end: seed + 1,
+            };
+            let chained = input
+                .resolve_intervals(vec![seed_interval], "seed", "location")
+                .unwrap();
+            assert_eq!(get_interval_mapping(seed_interval, &composed), chained);
+        }
+        let target = Interval { start: 46, end: 47 };
+        assert_eq!(target.to_string(), "46..47");
+        let seeds = input.find_sources(target, "seed", "location").unwrap();
+        assert!(seeds.iter().any(|i| i.start <= 82 && 82 < i.end));
+        for interval in seeds {
+            for seed in interval.start..interval.end {
+                assert_eq!(
+                    get_interval_mapping(
+                        Interval {
+                            start: seed,
+                            end: seed + 1
+                        },
+                        &composed
+                    ),
+                    vec![target]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_overlaps_and_gaps() {
+        let sample_input = "seeds: 1
+
+a-to-b map:
+15 10 10
+20 15 10
+10 30 5
+";
+        let reports = parse_input(sample_input).unwrap().validate();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(
+            reports[0].diagnostics,
+            vec![
+                Diagnostic::OverlappingSources {
+                    line: Some(4),
+                    other_line: Some(5)
+                },
+                Diagnostic::OverlappingDestinations {
+                    line: Some(4),
+                    other_line: Some(5)
+                },
+                Diagnostic::Gap {
+                    start: 0,
+                    end: 10,
+                    previous_line: None,
+                    next_line: Some(4)
+                },
+                Diagnostic::Gap {
+                    start: 25,
+                    end: 30,
+                    previous_line: Some(5),
+                    next_line: Some(6)
+                },
+                Diagnostic::DestinationInGap {
+                    line: Some(5),
+                    start: 25,
+                    end: 30
+                },
+            ]
+        );
+        let messages: Vec<String> = reports[0]
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "line 4 and line 5 map the same source values",
+                "line 4 and line 5 map to the same destination values",
+                "source values 0..10 below line 4 are not mapped",
+                "source values 25..30 between line 5 and line 6 are not mapped",
+                "line 5 maps to 25..30, where unmapped source values map to themselves",
+            ]
+        );
+    }
+}
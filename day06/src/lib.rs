@@ -0,0 +1,107 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, InputLine, Solution};
+use std::fmt::Display;
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    races: Vec<Race>,
+}
+
+fn parse_values(line: Option<InputLine>, prefix: &'static str) -> Result<Vec<u64>, Error> {
+    let line = line.ok_or_else(|| Error::new(ErrorKind::Malformed(prefix), ""))?;
+    let values = line.text.strip_prefix(prefix).ok_or_else(|| line.malformed(prefix))?;
+    line.parse_numbers(values)
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let mut lines = numbered_lines(input_str);
+    let times = parse_values(lines.next(), "Time:")?;
+    let distances = parse_values(lines.next(), "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(Error::new(ErrorKind::Malformed("as many distances as times"), ""));
+    }
+    Ok(Input{races: times.into_iter().zip(distances).map(|(time, distance)|Race{time,distance}).collect()})
+}
+
+// Part two reads the numbers on each line as one number with the spaces removed.
+fn join_races(races: &[Race]) -> Result<Race, Error> {
+    let join = |values: Vec<String>| {
+        let joined = values.concat();
+        joined.parse().map_err(|_| Error::new(ErrorKind::InvalidNumber, joined))
+    };
+    let time = join(races.iter().map(|race| race.time.to_string()).collect())?;
+    let distance = join(races.iter().map(|race| race.distance.to_string()).collect())?;
+    Ok(Race{ time, distance})
+}
+
+fn calculate_race(race: &Race) -> u64 {
+    let mut result = 0;
+    for time in 0..race.time {
+        let speed = race.time - time;
+        let distance = speed * time;
+        if distance > race.distance {
+            result += 1;
+        }
+    }
+    result
+}
+
+fn calculate_part_one(input: &Input) -> u64 {
+    input.races.iter().map(calculate_race).product()
+}
+
+fn calculate_part_two(input: &Input) -> Result<u64, Error> {
+    Ok(calculate_race(&join_races(&input.races)?))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day06>("day06", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result,  288);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(71503));
+    }
+}
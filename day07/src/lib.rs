@@ -0,0 +1,225 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, InputLine, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    TreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Copy, Hash)]
+struct Card {
+    value: u32,
+    symbol: char,
+}
+
+// With the joker rule J is the weakest card and counts as whatever helps the hand most.
+const JOKER_VALUE: u32 = 1;
+
+impl Card {
+    fn as_joker(self) -> Self {
+        if self.symbol == 'J' {
+            Card {
+                value: JOKER_VALUE,
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(item: char) -> Result<Self, Self::Error> {
+        let value = match item {
+            '2'..='9' => item as u32 - '0' as u32,
+            'T' => 10,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => return Err(Error::invalid_tile(item)),
+        };
+        Ok(Card {
+            symbol: item,
+            value,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Hand {
+    cards: Vec<Card>,
+    bid: u32,
+}
+
+fn parse_hand(line: InputLine) -> Result<Hand, Error> {
+    // line = "32T3K 765";
+    let (cards_str, bid_str) = line
+        .text
+        .split_once(' ')
+        .ok_or_else(|| line.malformed("`<cards> <bid>`"))?;
+    let bid = line.parse(bid_str.trim())?;
+    let cards = cards_str
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| Card::try_from(c).map_err(|err| err.at(line.number, idx + 1)))
+        .collect::<Result<Vec<Card>, Error>>()?;
+    if cards.len() != 5 {
+        return Err(line.error(ErrorKind::Malformed("five cards"), cards_str));
+    }
+    Ok(Hand { bid, cards })
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.get_hand_type() == other.get_hand_type() {
+            self.cards.cmp(&other.cards)
+        } else {
+            self.get_hand_type().cmp(&other.get_hand_type())
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Hand {
+    fn with_jokers(&self) -> Self {
+        Hand {
+            cards: self.cards.iter().map(|card| card.as_joker()).collect(),
+            bid: self.bid,
+        }
+    }
+
+    fn get_hand_type(&self) -> HandType {
+        let mut counts: HashMap<&Card, u32> = HashMap::new();
+        let mut j_count: u32 = 0;
+        for card in &self.cards {
+            if card.value == JOKER_VALUE {
+                j_count += 1
+            } else {
+                counts.entry(card).and_modify(|counter| *counter += 1).or_insert(1);
+            }
+        }
+        let mut vals: Vec<u32> = counts.into_values().collect();
+        vals.sort();
+        let vals_len = &vals.len();
+        if *vals_len == 0 && j_count == 5{
+            // all cards are jocker
+            vals.push(j_count);
+        }else if j_count > 0 {
+            // Add jocker count to largest group
+            vals[vals_len-1] += j_count;
+        }
+        match vals[..] {
+            [5] => HandType::FiveOfAKind,
+            [1, 4] => HandType::FourOfAKind,
+            [2, 3] => HandType::FullHouse,
+            [1, 1, 3] => HandType::TreeOfAKind,
+            [1, 2, 2] => HandType::TwoPair,
+            [1, 1, 1, 2] => HandType::OnePair,
+            [1, 1, 1, 1, 1] => HandType::HighCard,
+            _ => unreachable!("hands are checked to have five cards while parsing"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    hands: Vec<Hand>,
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let hands = numbered_lines(input_str)
+        .map(parse_hand)
+        .collect::<Result<_, _>>()?;
+    Ok(Input { hands })
+}
+
+fn calculate_winnings(mut hands: Vec<Hand>) -> u32 {
+    hands.sort();
+    hands.into_iter().enumerate().map(|(idx, hand)| (idx as u32 +1)* hand.bid).sum()
+}
+
+fn calculate_part_one(input: &Input) -> u32 {
+    calculate_winnings(input.hands.clone())
+}
+
+fn calculate_part_two(input: &Input) -> u32 {
+    calculate_winnings(input.hands.iter().map(Hand::with_jokers).collect())
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day07>("day07", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hand_type_ord() {
+        assert!(HandType::FiveOfAKind > HandType::FourOfAKind);
+    }
+
+    const SAMPLE_INPUT: &str = "
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 6440);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 5905);
+    }
+
+    #[test]
+    fn rejects_short_hand() {
+        let err = parse_input("32T3K 765\nKK67 28").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 1: expected five cards, found `KK67`");
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    day08::run()
+}
@@ -0,0 +1,195 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, InputLine, Solution};
+use std::{collections::HashMap};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Input {
+    route: String,
+    map: HashMap<String, Vec<String>>,
+}
+
+fn parse_node(line: InputLine) -> Result<(String, Vec<String>), Error> {
+    let expected = "`<node> = (<left>, <right>)`";
+    let (key, last) = line.text.split_once(" = ").ok_or_else(|| line.malformed(expected))?;
+    // last = (BBB, CCC)
+    // remove parntesis
+    let last = last
+        .strip_prefix('(')
+        .and_then(|last| last.strip_suffix(')'))
+        .ok_or_else(|| line.malformed(expected))?;
+    // last = BBB, CCC
+    let (left, right) = last.split_once(", ").ok_or_else(|| line.malformed(expected))?;
+    Ok((key.to_string(), vec![left.to_string(), right.to_string()]))
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let mut lines = numbered_lines(input_str);
+    let route_line = lines.next().ok_or_else(|| Error::new(ErrorKind::Malformed("a route"), ""))?;
+    if let Some((idx, c)) = route_line.text.chars().enumerate().find(|(_, c)| *c != 'L' && *c != 'R') {
+        return Err(Error::invalid_tile(c).at(route_line.number, idx + 1));
+    }
+    let route = route_line.text.to_string();
+    let mut map = HashMap::new();
+    let mut targets = Vec::new();
+    for line in lines {
+        let (key, value) = parse_node(line)?;
+        targets.extend(value.iter().map(|target| (line, target.clone())));
+        map.insert(key, value);
+    }
+    // Every node we can walk to has to be defined, so walking never falls off the map.
+    for (line, target) in targets {
+        if !map.contains_key(&target) {
+            let part = line.text.rfind(target.as_str()).map_or(line.text, |idx| &line.text[idx..idx + target.len()]);
+            return Err(line.error(ErrorKind::Malformed("a defined node"), part));
+        }
+    }
+
+    Ok(Input { route, map })
+}
+
+fn calculate_steps(input: &Input, start: String, is_end: impl Fn(&str) -> bool) -> Result<u128, Error> {
+    if !input.map.contains_key(&start) {
+        return Err(Error::new(ErrorKind::NoSolution("start node is not in the map"), start));
+    }
+    // Once we walked more steps than there are (node, route position) pairs we are going in circles.
+    let max_moves = (input.map.len() * input.route.len()) as u128;
+    let mut move_count = 0;
+    let mut current_move = start;
+    while move_count <= max_moves {
+        for direction in &input.route.chars().collect::<Vec<char>>() {
+            if *direction == 'L' {
+                current_move = input.map[&current_move][0].clone();
+            } else {
+                current_move = input.map[&current_move][1].clone();
+            }
+            move_count += 1;
+            if is_end(&current_move) {
+                return Ok(move_count);
+            }
+        }
+    }
+    Err(Error::no_solution("the route never reaches an end node"))
+}
+
+fn lcm(a: u128, b: u128) -> u128 {
+    let mut a_remaining = a;
+    let mut b_remaining = b;
+    let mut result: u128 = 1;
+    let mut divisor = 2;
+    while a_remaining > 1 || b_remaining > 1 {
+        let a_rem = a_remaining % divisor;
+        let b_rem = b_remaining % divisor;
+        if  a_rem == 0 || b_rem == 0 {
+            if a_rem == 0 {
+                a_remaining /= divisor;
+            }
+            if b_rem == 0 {
+                b_remaining /= divisor;
+            }
+            result *= divisor;
+        } else {
+            divisor += 1
+        }
+
+
+    }
+    result 
+}
+
+fn calculate_part_one(input: &Input) -> Result<u128, Error> {
+    calculate_steps(input, "AAA".to_string(), |m| m == "ZZZ")
+}
+
+fn calculate_part_two(input: &Input) -> Result<u128, Error> {
+    let current_moves: Vec<String> = input.map.keys().filter(|k|k.ends_with('A')).cloned().collect();
+    let steps = current_moves.into_iter().map(|m|calculate_steps(input, m, |m| m.ends_with('Z'))).collect::<Result<Vec<u128>, Error>>()?;
+    Ok(steps.into_iter().fold(1, lcm))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day08>("day08", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_works() {
+        let sample_input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+        let result = calculate_part_one(&parse_input(sample_input).unwrap());
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn part_one_repeats_route() {
+        let sample_input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+        let result = calculate_part_one(&parse_input(sample_input).unwrap());
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let sample_input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+        let result = calculate_part_two(&parse_input(sample_input).unwrap());
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn reports_circular_route() {
+        let sample_input = "L
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+        let result = calculate_part_one(&parse_input(sample_input).unwrap());
+        assert_eq!(result, Err(Error::no_solution("the route never reaches an end node")));
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_core::{numbered_lines, Error, Solution};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Reading {
+    // 1 based line in the input
+    line: usize,
+    values: Vec<i32>,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    readings: Vec<Reading>,
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let readings = numbered_lines(input_str)
+        .map(|l|Ok(Reading { line: l.number, values: l.parse_numbers(l.text)? }))
+        .collect::<Result<_, Error>>()?;
+    Ok(Input { readings })
+}
+
+// None when a difference does not fit in an i32.
+fn get_differences(values: &[i32]) -> Option<Vec<i32>> {
+    values.iter().zip(&values[1..]).map(|(first,second)| second.checked_sub(*first)).collect()
+}
+
+fn get_next_number(values: &[i32]) -> Option<i32> {
+    if values.iter().all(|n|*n == 0) {
+        Some(0)
+    } else {
+        values.last().unwrap().checked_add(get_next_number(&get_differences(values)?)?)
+    }
+}
+
+fn get_prev_number(values: &[i32]) -> Option<i32> {
+    if values.iter().all(|n|*n == 0) {
+        Some(0)
+    } else {
+        values.first().unwrap().checked_sub(get_prev_number(&get_differences(values)?)?)
+    }
+}
+
+fn overflow() -> Error {
+    Error::no_solution("the extrapolated values do not fit in 32 bits")
+}
+
+fn sum_extrapolated(input: &Input, extrapolate: fn(&[i32]) -> Option<i32>) -> Result<i32, Error> {
+    input.readings.iter().try_fold(0i32, |sum, reading| {
+        let number = extrapolate(&reading.values).ok_or_else(|| overflow().at_line(reading.line))?;
+        sum.checked_add(number).ok_or_else(overflow)
+    })
+}
+
+fn calculate_part_one(input: &Input) -> Result<i32, Error> {
+    sum_extrapolated(input, get_next_number)
+}
+
+fn calculate_part_two(input: &Input) -> Result<i32, Error> {
+    sum_extrapolated(input, get_prev_number)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day09>("day09", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::ErrorKind;
+
+    const SAMPLE_INPUT: &str = "
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(114));
+
+        let overflowing = parse_input("1 2\n2000000000 -2000000000 2000000000").unwrap();
+        let err = calculate_part_one(&overflowing).unwrap_err();
+        assert_eq!((err.kind, err.line), (ErrorKind::NoSolution("the extrapolated values do not fit in 32 bits"), Some(2)));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&parse_input(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(2));
+    }
+}
@@ -0,0 +1,553 @@
+use aoc_core::{
+    exit_with_error, get_neighbour, input_source_from_args, numbered_lines, parse_grid, Direction,
+    Error, ErrorKind, Grid, Location, Solution,
+};
+use std::collections::HashSet;
+use std::fmt::Display;
+
+mod report;
+
+pub use report::{analyse_loops, Fragment, LoopReport, Orientation, PipeLoop};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Tile {
+    // Only seen while parsing, replaced by the pipe that is really under it.
+    Start,
+    Empty,
+    NorthSouth,
+    NorthEast,
+    NorthWest,
+    EastWest,
+    EastSouth,
+    WestSouth,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(input: char) -> Result<Self, Self::Error> {
+        match input {
+            'S' => Ok(Tile::Start),
+            '.' => Ok(Tile::Empty),
+            '|' => Ok(Tile::NorthSouth),
+            'L' => Ok(Tile::NorthEast),
+            'J' => Ok(Tile::NorthWest),
+            '-' => Ok(Tile::EastWest),
+            'F' => Ok(Tile::EastSouth),
+            '7' => Ok(Tile::WestSouth),
+            _ => Err(Error::invalid_tile(input)),
+        }
+    }
+}
+
+impl Tile {
+    const PIPES: [Tile; 6] = [
+        Tile::NorthSouth,
+        Tile::NorthEast,
+        Tile::NorthWest,
+        Tile::EastWest,
+        Tile::EastSouth,
+        Tile::WestSouth,
+    ];
+
+    // Directions the pipe connects to.
+    fn connections(&self) -> &'static [Direction] {
+        match self {
+            Tile::Start | Tile::Empty => &[],
+            Tile::NorthSouth => &[Direction::Up, Direction::Down],
+            Tile::NorthEast => &[Direction::Up, Direction::Right],
+            Tile::NorthWest => &[Direction::Up, Direction::Left],
+            Tile::EastWest => &[Direction::Right, Direction::Left],
+            Tile::EastSouth => &[Direction::Right, Direction::Down],
+            Tile::WestSouth => &[Direction::Left, Direction::Down],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<Tile>,
+    start: Location,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum AnalysisTile {
+    Out,
+    In,
+    Pipe,
+}
+
+type AnalysisGrid = Grid<AnalysisTile>;
+
+#[derive(Debug)]
+struct Analysis {
+    grid: AnalysisGrid,
+}
+
+impl Analysis {
+    fn new(input: &Input, seen: &HashSet<Location>) -> Self {
+        let mut grid: AnalysisGrid = Vec::new();
+
+        for y in 0..input.grid.len() {
+            let mut row1 = Vec::new();
+            let mut row2 = Vec::new();
+            let mut row3 = Vec::new();
+
+            for x in 0..input.grid[0].len() {
+                let tile = if seen.contains(&Location { x, y }) {
+                    input.grid[y][x]
+                } else {
+                    Tile::Empty
+                };
+                let col1;
+                let col2;
+                let col3;
+                match tile {
+                    Tile::Start | Tile::Empty => {
+                        col1 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                        col2 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                        col3 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                    }
+                    Tile::NorthSouth => {
+                        col1 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                        col2 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                        col3 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                    }
+                    Tile::NorthEast => {
+                        col1 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                        col2 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::Pipe];
+                        col3 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                    }
+                    Tile::NorthWest => {
+                        col1 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                        col2 = [AnalysisTile::Pipe, AnalysisTile::Pipe, AnalysisTile::In];
+                        col3 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                    }
+                    Tile::EastWest => {
+                        col1 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                        col2 = [AnalysisTile::Pipe, AnalysisTile::Pipe, AnalysisTile::Pipe];
+                        col3 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                    }
+                    Tile::EastSouth => {
+                        col1 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                        col2 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::Pipe];
+                        col3 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                    }
+                    Tile::WestSouth => {
+                        col1 = [AnalysisTile::In, AnalysisTile::In, AnalysisTile::In];
+                        col2 = [AnalysisTile::Pipe, AnalysisTile::Pipe, AnalysisTile::In];
+                        col3 = [AnalysisTile::In, AnalysisTile::Pipe, AnalysisTile::In];
+                    }
+                }
+                row1.extend(col1);
+                row2.extend(col2);
+                row3.extend(col3);
+            }
+            grid.push(row1);
+            grid.push(row2);
+            grid.push(row3);
+        }
+
+        for y in 0..grid.len() {
+            let loc1 = Location { x: 0, y };
+            let loc2 = Location {
+                x: grid[0].len() - 1,
+                y,
+            };
+            Analysis::flood_fill(&mut grid, loc1);
+            Analysis::flood_fill(&mut grid, loc2);
+        }
+
+        for x in 0..grid[0].len() {
+            let loc1 = Location { x, y: 0 };
+            let loc2 = Location {
+                x,
+                y: grid.len() - 1,
+            };
+            Analysis::flood_fill(&mut grid, loc1);
+            Analysis::flood_fill(&mut grid, loc2);
+        }
+        Analysis { grid }
+    }
+
+    fn count_inner_tiles(&self) -> i32 {
+        let grid = &self.grid;
+        let mut count_grid: Vec<Vec<i32>> = vec![vec![0;self.grid[0].len()/3];self.grid.len()/3];
+        for y in 0..grid.len() {
+            for x in 0..grid[y].len() {
+                let tile = &grid[y][x];
+                if tile == &AnalysisTile::In {
+                    count_grid[y/3][x/3] += 1;
+                }
+            }
+        }
+        count_grid.iter().map(|row| row.iter().map(|c| if c == &9 {1} else {0}).sum::<i32>()).sum()
+    }
+
+    // Marks every In tile reachable from loc as Out. Uses an explicit stack, a region can
+    // span millions of tiles.
+    fn flood_fill(grid: &mut AnalysisGrid, loc: Location) {
+        let mut stack = vec![loc];
+        while let Some(current) = stack.pop() {
+            if grid[current.y][current.x] != AnalysisTile::In {
+                continue;
+            }
+            grid[current.y][current.x] = AnalysisTile::Out;
+            stack.extend(
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(|direction| get_neighbour(grid, &current, direction)),
+            );
+        }
+    }
+}
+
+impl Input {
+    fn get_tile(&self, location: &Location) -> Tile {
+        self.grid[location.y][location.x]
+    }
+
+    // In bounds neighbours the tile at location has an opening towards.
+    fn openings(&self, location: &Location) -> impl Iterator<Item = (Direction, Location)> + '_ {
+        let location = *location;
+        self.get_tile(&location)
+            .connections()
+            .iter()
+            .filter_map(move |direction| {
+                get_neighbour(&self.grid, &location, *direction).map(|next| (*direction, next))
+            })
+    }
+
+    // Openings where the neighbour has an opening back, so the two pipes are joined.
+    fn connected(&self, location: &Location) -> impl Iterator<Item = (Direction, Location)> + '_ {
+        self.openings(location).filter(|(direction, next)| {
+            self.get_tile(next)
+                .connections()
+                .contains(&direction.opposite())
+        })
+    }
+}
+
+fn parse_input(input_str: &str) -> Result<Input, Error> {
+    let mut grid: Grid<Tile> = parse_grid(input_str)?;
+    let starts: Vec<Location> = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, tile)| **tile == Tile::Start)
+                .map(move |(x, _)| Location { x, y })
+        })
+        .collect();
+    let start = match starts[..] {
+        [start] => start,
+        _ => {
+            let found = format!("{} start tiles", starts.len());
+            return Err(Error::new(ErrorKind::Malformed("exactly one start tile `S`"), found));
+        }
+    };
+    // Neighbours with a pipe pointing back at the start tile.
+    let connected: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|direction| {
+            get_neighbour(&grid, &start, *direction).is_some_and(|next| {
+                grid[next.y][next.x]
+                    .connections()
+                    .contains(&direction.opposite())
+            })
+        })
+        .collect();
+    let pipe = Tile::PIPES.into_iter().find(|pipe| {
+        connected.len() == 2 && connected.iter().all(|d| pipe.connections().contains(d))
+    });
+    match pipe {
+        Some(pipe) => grid[start.y][start.x] = pipe,
+        None => {
+            let kind = ErrorKind::Malformed("exactly two pipes connecting to the start tile");
+            let error = numbered_lines(input_str)
+                .find_map(|line| {
+                    let idx = line.text.find('S')?;
+                    Some(line.error(kind, &line.text[idx..idx + 1]))
+                })
+                .unwrap_or_else(|| Error::new(kind, "S"));
+            return Err(error);
+        }
+    }
+    Ok(Input { grid, start })
+}
+
+// Walks the loop from the start tile, returns the tiles of the loop and
+// the number of steps to the farthest one.
+fn find_loop(input: &Input) -> (HashSet<Location>, i32) {
+    let start_location = input.start;
+    let mut seen: HashSet<Location> = HashSet::new();
+    let mut frontier: Vec<Location> = Vec::new();
+    let mut steps = 0;
+    seen.insert(start_location);
+    frontier.push(start_location);
+    while !frontier.is_empty() {
+        let mut new_frontier = Vec::new();
+        for current in &frontier {
+            for (_, next) in input.connected(current) {
+                if !seen.contains(&next) {
+                    seen.insert(next);
+                    new_frontier.push(next);
+                }
+            }
+        }
+
+        if new_frontier.is_empty() {
+            break;
+        } else {
+            steps += 1;
+            frontier = new_frontier;
+        }
+    }
+    (seen, steps)
+}
+
+// Follows the pipes from `from` until it is reached again or the pipe ends, returns the tiles
+// in walk order and whether they close into a loop.
+fn walk(input: &Input, from: Location) -> (Vec<Location>, bool) {
+    let mut tiles = vec![from];
+    let Some((mut direction, mut current)) = input.connected(&from).next() else {
+        return (tiles, false);
+    };
+    while current != from {
+        tiles.push(current);
+        match input
+            .connected(&current)
+            .find(|(dir, _)| *dir != direction.opposite())
+        {
+            Some(step) => (direction, current) = step,
+            None => return (tiles, false),
+        }
+    }
+    (tiles, true)
+}
+
+// The tiles of the loop through the start tile, in loop order.
+fn walk_loop(input: &Input) -> Result<Vec<Location>, Error> {
+    match walk(input, input.start) {
+        (tiles, true) => Ok(tiles),
+        _ => Err(Error::no_solution("the loop through the start tile is broken")),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AreaMethod {
+    // Upsamples the grid 3x and flood fills the outside.
+    FloodFill,
+    // Shoelace formula over the loop, then Pick's theorem for the tiles inside.
+    Shoelace,
+}
+
+// Shoelace formula, positive when the loop runs clockwise on screen (y grows downwards).
+fn twice_signed_area(tiles: &[Location]) -> i64 {
+    tiles
+        .iter()
+        .zip(tiles.iter().cycle().skip(1))
+        .map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64)
+        .sum()
+}
+
+fn count_enclosed_by_shoelace(tiles: &[Location]) -> i32 {
+    // Pick: area = inside + boundary / 2 - 1
+    ((twice_signed_area(tiles).abs() - tiles.len() as i64) / 2 + 1) as i32
+}
+
+pub fn count_enclosed_tiles(input: &Input, method: AreaMethod) -> Result<i32, Error> {
+    match method {
+        AreaMethod::FloodFill => {
+            let (seen, _) = find_loop(input);
+            Ok(Analysis::new(input, &seen).count_inner_tiles())
+        }
+        AreaMethod::Shoelace => Ok(count_enclosed_by_shoelace(&walk_loop(input)?)),
+    }
+}
+
+fn calculate_part_one(input: &Input) -> Result<i32, Error> {
+    let (_, steps) = find_loop(input);
+    Ok(steps)
+}
+
+fn calculate_part_two(input: &Input) -> Result<i32, Error> {
+    count_enclosed_tiles(input, AreaMethod::Shoelace)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        parse_input(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const USAGE: &str = "Usage: day10 [PATH|-] [--loops]";
+
+// With `--loops` every pipe loop and fragment is listed instead of the answers.
+pub fn run() {
+    let mut loops = false;
+    let source = input_source_from_args(Day10::DAY, INPUT_PATH, USAGE, |flag, _| {
+        match flag {
+            "--loops" => loops = true,
+            _ => return Err(USAGE.to_string()),
+        }
+        Ok(())
+    })
+    .unwrap_or_else(|err| exit_with_error(&err));
+    if !loops {
+        return aoc_core::run::<Day10>("day10", INPUT_PATH);
+    }
+    let input_str = source.read().unwrap_or_else(|err| exit_with_error(&err));
+    let input = parse_input(&input_str)
+        .unwrap_or_else(|err| exit_with_error(&err.in_day(Day10::DAY).to_string()));
+    print!("{}", analyse_loops(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_works() {
+        let sample_input = "
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+        let result = calculate_part_one(&parse_input(sample_input).unwrap());
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let sample_input = "
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+        let input = parse_input(sample_input).unwrap();
+        assert_eq!(calculate_part_two(&input), Ok(4));
+        assert_eq!(count_enclosed_tiles(&input, AreaMethod::FloodFill), Ok(4));
+    }
+
+    #[test]
+    fn part_two_squeezes_between_pipes() {
+        let sample_input = "
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+        let input = parse_input(sample_input).unwrap();
+        assert_eq!(calculate_part_two(&input), Ok(8));
+        assert_eq!(count_enclosed_tiles(&input, AreaMethod::FloodFill), Ok(8));
+    }
+
+    #[test]
+    fn part_two_ignores_junk_pipes() {
+        let sample_input = "
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+        let input = parse_input(sample_input).unwrap();
+        assert_eq!(calculate_part_two(&input), Ok(10));
+        assert_eq!(count_enclosed_tiles(&input, AreaMethod::FloodFill), Ok(10));
+    }
+
+    #[test]
+    fn start_is_replaced_by_connecting_pipe() {
+        let input = parse_input(".....\n.S-7.\n.|.|.\n.L-J.\n.....\n").unwrap();
+        assert_eq!(input.start, Location { x: 1, y: 1 });
+        assert_eq!(input.get_tile(&input.start), Tile::EastSouth);
+
+        let crossing = parse_input("\n.|.\n-S-\n.|.\n").unwrap_err();
+        assert_eq!((crossing.line, crossing.column), (Some(3), Some(2)));
+        assert!(parse_input("...\n.S-\n...\n").is_err());
+        assert!(parse_input("...\n.S.\n...\n").is_err());
+    }
+
+    #[test]
+    fn reports_every_loop_and_fragment() {
+        let input = parse_input("F7.F-7\nLJ.|.|\n-..L-J\n.FS...\n.LJ.|.\n").unwrap();
+        let report = analyse_loops(&input);
+        let summary: Vec<_> = report
+            .loops
+            .iter()
+            .map(|l| (l.tiles[0], l.tiles.len(), l.farthest, l.orientation, l.enclosed))
+            .collect();
+        let at = |x, y| Location { x, y };
+        assert_eq!(
+            summary,
+            vec![
+                (at(0, 0), 4, at(1, 1), Orientation::Clockwise, 0),
+                (at(3, 0), 8, at(5, 2), Orientation::Clockwise, 1),
+                (at(2, 3), 4, at(1, 4), Orientation::CounterClockwise, 0),
+            ]
+        );
+        let main_loop = report.main_loop().unwrap();
+        assert_eq!(Ok(main_loop.farthest_steps as i32), calculate_part_one(&input));
+        let fragments: Vec<_> = report.fragments.iter().map(|f| f.tiles.clone()).collect();
+        assert_eq!(fragments, vec![vec![at(0, 2)], vec![at(4, 4)]]);
+    }
+
+    #[test]
+    fn large_generated_maps_work() {
+        // A loop around the top half of a 1000x1000 map, the bottom half one big outside region.
+        let (width, height) = (1000, 1000);
+        let mut rows = vec![format!("S{}7", "-".repeat(width - 2))];
+        for _ in 1..height / 2 - 1 {
+            rows.push(format!("|{}|", ".".repeat(width - 2)));
+        }
+        rows.push(format!("L{}J", "-".repeat(width - 2)));
+        for _ in height / 2..height {
+            rows.push(".".repeat(width));
+        }
+        let input = parse_input(&rows.join("\n")).unwrap();
+        let enclosed = ((width - 2) * (height / 2 - 2)) as i32;
+        assert_eq!(calculate_part_one(&input), Ok((width - 1 + height / 2 - 1) as i32));
+        assert_eq!(calculate_part_two(&input), Ok(enclosed));
+        assert_eq!(
+            count_enclosed_tiles(&input, AreaMethod::FloodFill),
+            Ok(enclosed)
+        );
+    }
+}
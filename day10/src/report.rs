@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use aoc_core::Location;
+
+use crate::{count_enclosed_by_shoelace, twice_signed_area, walk, Input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipeLoop {
+    // In walk order, starting at `S` when the loop goes through it, otherwise at its first tile
+    // in reading order.
+    pub tiles: Vec<Location>,
+    // Farthest along the pipes from the first tile.
+    pub farthest: Location,
+    pub farthest_steps: usize,
+    pub orientation: Orientation,
+    pub enclosed: i32,
+}
+
+// Connected pipes that do not close into a loop, from one end to the other.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    pub tiles: Vec<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoopReport {
+    pub start: Location,
+    pub loops: Vec<PipeLoop>,
+    pub fragments: Vec<Fragment>,
+}
+
+impl LoopReport {
+    pub fn main_loop(&self) -> Option<&PipeLoop> {
+        self.loops.iter().find(|l| l.tiles[0] == self.start)
+    }
+}
+
+// Every pipe tile joined to the one at `from`.
+fn component(input: &Input, from: Location) -> Vec<Location> {
+    let mut seen = HashSet::from([from]);
+    let mut stack = vec![from];
+    while let Some(current) = stack.pop() {
+        for (_, next) in input.connected(&current) {
+            if seen.insert(next) {
+                stack.push(next);
+            }
+        }
+    }
+    seen.into_iter().collect()
+}
+
+fn pipe_loop(tiles: Vec<Location>) -> PipeLoop {
+    // Every tile has two neighbours on the loop, the one halfway round is the farthest.
+    let farthest_steps = tiles.len() / 2;
+    let orientation = if twice_signed_area(&tiles) > 0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::CounterClockwise
+    };
+    PipeLoop {
+        farthest: tiles[farthest_steps],
+        farthest_steps,
+        orientation,
+        enclosed: count_enclosed_by_shoelace(&tiles),
+        tiles,
+    }
+}
+
+// Splits all pipes of the map into closed loops and dangling fragments, in reading order of
+// their first tile.
+pub fn analyse_loops(input: &Input) -> LoopReport {
+    let mut seen: HashSet<Location> = HashSet::new();
+    let mut loops = Vec::new();
+    let mut fragments = Vec::new();
+    for (y, row) in input.grid.iter().enumerate() {
+        for (x, tile) in row.iter().enumerate() {
+            let location = Location { x, y };
+            if tile.connections().is_empty() || seen.contains(&location) {
+                continue;
+            }
+            let tiles = component(input, location);
+            seen.extend(tiles.iter().copied());
+            let end = tiles
+                .iter()
+                .filter(|tile| input.connected(tile).count() < 2)
+                .min_by_key(|tile| (tile.y, tile.x));
+            match end {
+                Some(end) => fragments.push(Fragment {
+                    tiles: walk(input, *end).0,
+                }),
+                None => {
+                    let from = if tiles.contains(&input.start) {
+                        input.start
+                    } else {
+                        location
+                    };
+                    loops.push(pipe_loop(walk(input, from).0));
+                }
+            }
+        }
+    }
+    LoopReport {
+        start: input.start,
+        loops,
+        fragments,
+    }
+}
+
+fn format_location(location: &Location) -> String {
+    format!("({}, {})", location.x, location.y)
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orientation::Clockwise => write!(f, "clockwise"),
+            Orientation::CounterClockwise => write!(f, "counter-clockwise"),
+        }
+    }
+}
+
+impl Display for LoopReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} loops, {} fragments",
+            self.loops.len(),
+            self.fragments.len()
+        )?;
+        for pipe_loop in &self.loops {
+            let from = &pipe_loop.tiles[0];
+            let label = if *from == self.start { "S " } else { "" };
+            writeln!(
+                f,
+                "Loop from {}{}: length {}, farthest {} after {} steps, {}, {} enclosed",
+                label,
+                format_location(from),
+                pipe_loop.tiles.len(),
+                format_location(&pipe_loop.farthest),
+                pipe_loop.farthest_steps,
+                pipe_loop.orientation,
+                pipe_loop.enclosed
+            )?;
+        }
+        for fragment in &self.fragments {
+            writeln!(
+                f,
+                "Fragment from {} to {}: length {}",
+                format_location(&fragment.tiles[0]),
+                format_location(fragment.tiles.last().unwrap()),
+                fragment.tiles.len()
+            )?;
+        }
+        Ok(())
+    }
+}
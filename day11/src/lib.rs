@@ -1,3 +1,6 @@
+use aoc_core::{parse_grid, Error, Grid, Location, Solution};
+use std::fmt::Display;
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum Tile {
     Empty,
@@ -6,28 +9,20 @@ enum Tile {
 
 impl TryFrom<char> for Tile {
 
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
             '#' => Ok(Tile::Galaxy),
             '.' => Ok(Tile::Empty),
-            _ => Err("Cannot Parse Tile"),
+            _ => Err(Error::invalid_tile(value)),
         }
     }
 }
 
-type Grid = Vec<Vec<Tile>>;
-
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
-struct Location {
-    x: usize,
-    y: usize,
-}
-
 #[derive(Debug)]
-struct Input {
-    grid: Grid,
+pub struct Input {
+    grid: Grid<Tile>,
 }
 
 impl Input {
@@ -40,10 +35,10 @@ impl Input {
         let mut result = Vec::new();
         for a in 0..galaxies.len() {
             for b in a + 1..galaxies.len() {
-                result.push((galaxies[a].clone(), galaxies[b].clone()));
+                result.push((galaxies[a], galaxies[b]));
             }
         }
-        return result;
+        result
     }
 
     fn find_galaxies(&self) -> Vec<Location> {
@@ -56,20 +51,7 @@ impl Input {
                 }
             }
         }
-        return result;
-    }
-
-    fn print(&self) {
-        for row in &self.grid {
-            for tile in row {
-                match tile {
-                    Tile::Galaxy => print!("#"),
-                    _ => print!("."),
-                }
-            }
-            println!("");
-        }
-        println!("");
+        result
     }
 
     fn get_empty_rows(&self) -> Vec<usize> {
@@ -109,20 +91,15 @@ impl Input {
 
 impl TryFrom<&str> for Input {
 
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(input_str: &str) -> Result<Self, Self::Error> {
-        let grid = input_str
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|l| l.chars().map(Tile::try_from).collect())
-            .collect::<Result<Grid,Self::Error>>()?;
+        let grid = parse_grid(input_str)?;
         Ok(Input { grid })
     }
 }
 
-fn calculate(input: &mut Input, expansion_factor: u128) -> u128 {
-    input.print();
+fn calculate(input: &Input, expansion_factor: u128) -> u128 {
     let empty_rows = input.get_empty_rows();
     let empty_cols = input.get_empty_columns();
     let galaxy_tuples = input.get_galaxy_matches();
@@ -133,13 +110,13 @@ fn calculate(input: &mut Input, expansion_factor: u128) -> u128 {
         let upper_x = std::cmp::max(a.x, b.x);
         let lower_y = std::cmp::min(a.y, b.y);
         let upper_y = std::cmp::max(a.y, b.y);
-        let empty_rows_passed: u128 = (&empty_rows)
-            .into_iter()
+        let empty_rows_passed: u128 = empty_rows
+            .iter()
             .filter(|&row_y| lower_y < *row_y && *row_y < upper_y)
             .map(|_| 1)
             .sum();
-        let empty_cols_passed: u128 = (&empty_cols)
-            .into_iter()
+        let empty_cols_passed: u128 = empty_cols
+            .iter()
             .filter(|&row_x| lower_x < *row_x && *row_x < upper_x)
             .map(|_| 1)
             .sum();
@@ -152,20 +129,45 @@ fn calculate(input: &mut Input, expansion_factor: u128) -> u128 {
     result
 }
 
+fn calculate_part_one(input: &Input) -> u128 {
+    calculate(input, 2)
+}
+
+fn calculate_part_two(input: &Input) -> u128 {
+    calculate(input, 1000000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
 pub fn run() {
-    let input_str = include_str!("../input.txt");
-    let mut input = Input::try_from(input_str).unwrap();
-    let result = calculate(&mut input, 1000000);
-    println!("Result for day11a: {}", result);
+    aoc_core::run::<Day11>("day11", INPUT_PATH);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn it_works() {
-        let sample_input = "
+    const SAMPLE_INPUT: &str = "
 ...#......
 .......#..
 #.........
@@ -177,25 +179,17 @@ mod tests {
 .......#..
 #...#.....
 ";
-        let result = calculate(&mut Input::try_from(sample_input).unwrap(), 10);
-        assert_eq!(result, 1030);
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 374);
     }
 
     #[test]
-    fn it_works2() {
-        let sample_input = "
-...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....
-";
-        let result = calculate(&mut Input::try_from(sample_input).unwrap(), 100);
-        assert_eq!(result, 8410);
+    fn expansion_factor_scales_empty_lines() {
+        let input = Input::try_from(SAMPLE_INPUT).unwrap();
+        assert_eq!(calculate(&input, 10), 1030);
+        assert_eq!(calculate(&input, 100), 8410);
     }
 }
@@ -0,0 +1,376 @@
+use aoc_core::{numbered_lines, Error, InputLine, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+type ReturnType = u128;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+enum Status {
+    Unknown,
+    Damaged,
+    Working,
+}
+
+impl TryFrom<char> for Status {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Status::Damaged),
+            '.' => Ok(Status::Working),
+            '?' => Ok(Status::Unknown),
+            _ => Err(Error::invalid_tile(value)),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Line {
+    statuses: Vec<Status>,
+    groups: Vec<usize>,
+}
+
+impl TryFrom<InputLine<'_>> for Line {
+    type Error = Error;
+
+    fn try_from(line: InputLine) -> Result<Self, Self::Error> {
+        let (statuses_str, groups_str) = line
+            .text
+            .split_once(' ')
+            .ok_or_else(|| line.malformed("`<statuses> <group>,<group>,...`"))?;
+        let statuses = statuses_str
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| Status::try_from(c).map_err(|err| err.at(line.number, idx + 1)))
+            .collect::<Result<Vec<Status>, Self::Error>>()?;
+        let groups = groups_str
+            .trim()
+            .split(',')
+            .map(|n| line.parse::<usize>(n))
+            .collect::<Result<Vec<usize>, Self::Error>>()?;
+        Ok(Line { statuses, groups })
+    }
+}
+
+impl Line {
+    // Part two: five copies of the line, statuses joined by an unknown.
+    fn unfold(&self) -> Line {
+        let mut unfolded_statuses = Vec::new();
+        let mut unfolded_groups = Vec::new();
+
+        for i in 0..5 {
+            unfolded_statuses.extend(&self.statuses);
+            if i != 4 {
+                unfolded_statuses.push(Status::Unknown);
+            }
+            unfolded_groups.extend(&self.groups);
+        }
+        Line {
+            statuses: unfolded_statuses,
+            groups: unfolded_groups,
+        }
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = String::new();
+        for status in &self.statuses {
+            result += match status {
+                Status::Working => ".",
+                Status::Damaged => "#",
+                Status::Unknown => "?",
+            };
+        }
+        result += &format!(" {:?}", self.groups);
+        write!(f, "{}", result)
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<Line>,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let result = self.lines
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        write!(f, "{}", result)
+    }
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        let lines = numbered_lines(input_str)
+            .map(Line::try_from)
+            .collect::<Result<Vec<Line>, Self::Error>>()?;
+        Ok(Input { lines })
+    }
+}
+
+fn count_matches(
+    statuses: &[Status],
+    groups: &[usize],
+    cache: &mut HashMap<String, ReturnType>,
+) -> ReturnType {
+    //dbg!(statuses, groups);
+    // is status is empty finish recursion.
+    if statuses.is_empty() {
+        if groups.is_empty() {
+            return 1;
+        } else {
+            return 0;
+        }
+    }
+
+    //If value is in cache return it from cache.
+    let cache_key = format!("{}-{}", statuses.len(), groups.len());
+    if cache.contains_key(&cache_key) {
+        return *cache.get(&cache_key).unwrap();
+    }
+
+    let mut result = 0;
+
+    // Case where first element is not part of the first group
+    if [Status::Working, Status::Unknown].contains(&statuses[0]) {
+        result += count_matches(&statuses[1..], groups, cache);
+    }
+
+    //Case where first element is part of the group
+    if !groups.is_empty() {
+        let group = groups[0];
+        if statuses.len() >= group
+            && statuses[..group]
+                .iter()
+                .all(|s| [Status::Damaged, Status::Unknown].contains(s))
+        {
+            if statuses.len() == group {
+                result += count_matches(&statuses[group..], &groups[1..], cache);
+            } else if statuses.len() > group
+                && [Status::Working, Status::Unknown].contains(&statuses[group])
+            {
+                // Next element after the group is valid
+                // Do not send the next element to recursive call so we are removing it with the group.
+                result += count_matches(&statuses[group + 1..], &groups[1..], cache);
+            }
+        }
+    }
+
+    cache.insert(cache_key, result);
+    result
+}
+
+fn get_match_count(line: &Line) -> ReturnType {
+    count_matches(&line.statuses, &line.groups, &mut HashMap::new())
+}
+
+fn calculate_part_one(input: &Input) -> ReturnType {
+    input.lines.iter().map(get_match_count).sum()
+}
+
+fn calculate_part_two(input: &Input) -> ReturnType {
+    input.lines.iter().map(|line| get_match_count(&line.unfold())).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+pub fn run() {
+    aoc_core::run::<Day12>("day12", INPUT_PATH);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_one_works() {
+        let sample_input = "
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn part_one_line_1() {
+        let sample_input = "
+???.### 1,1,3
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn part_one_line_2() {
+        let sample_input = "
+.??..??...?##. 1,1,3
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn part_one_line_3() {
+        let sample_input = "
+?#?#?#?#?#?#?#? 1,3,1,6
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn part_one_line_4() {
+        let sample_input = "
+????.#...#... 4,1,1
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn part_one_line_5() {
+        let sample_input = "
+????.######..#####. 1,6,5
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn part_one_line_6() {
+        let sample_input = "
+?###???????? 3,2,1
+";
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let sample_input = "
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 525152);
+    }
+
+    #[test]
+    fn part_two_line_1() {
+        let sample_input = "
+???.### 1,1,3
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn part_two_line_2() {
+        let sample_input = "
+.??..??...?##. 1,1,3
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 16384);
+    }
+
+    #[test]
+    fn part_two_line_3() {
+        let sample_input = "
+?#?#?#?#?#?#?#? 1,3,1,6
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn part_two_line_4() {
+        let sample_input = "
+????.#...#... 4,1,1
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn part_two_line_5() {
+        let sample_input = "
+????.######..#####. 1,6,5
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 2500);
+    }
+
+    #[test]
+    fn part_two_line_6() {
+        let sample_input = "
+?###???????? 3,2,1
+";
+        let result = calculate_part_two(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, 506250);
+    }
+
+    #[test]
+    fn input_parsing_test() {
+        let sample_input = "
+.# 1
+";
+        let input = Input::try_from(sample_input).unwrap();
+        assert_eq!(
+            input.lines[0].unfold(),
+            Line {
+                statuses: vec![
+                    Status::Working,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Working,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Working,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Working,
+                    Status::Damaged,
+                    Status::Unknown,
+                    Status::Working,
+                    Status::Damaged
+                ],
+                groups: vec![1, 1, 1, 1, 1]
+            }
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use aoc_core::{
+    exit_with_error, input_source_from_args, parse_grid_lines, Error, ErrorKind, Grid, InputLine,
+    Location, Solution,
+};
+// use std::collections::HashMap;
+
+use std::fmt::Display;
+use std::ops::Range;
+
+type ReturnType = u128;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Stone,
+    Ash,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Tile::Stone),
+            '.' => Ok(Tile::Ash),
+            _ => Err(Error::invalid_tile(value)),
+        }
+    }
+}
+
+pub type Pattern = Grid<Tile>;
+
+#[derive(Debug)]
+pub struct Input {
+    patterns: Vec<Pattern>,
+    // Input line each pattern starts on.
+    first_lines: Vec<usize>,
+}
+
+fn format_pattern(pattern: &Pattern) -> String {
+    let mut result = String::new();
+    for line in pattern {
+        for tile in line {
+            result += match tile {
+                Tile::Ash => ".",
+                Tile::Stone => "#",
+            };
+        }
+        result += "\n";
+    }
+    result += "\n";
+    result
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut result = String::new();
+        for pattern in self.patterns.iter() {
+            result = format!("{}{}\n", result, format_pattern(pattern));
+        }
+        write!(f, "{}", result)
+    }
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        let mut patterns = Vec::new();
+        let mut first_lines = Vec::new();
+        let mut pattern_lines = Vec::new();
+        for (idx, text) in input_str.lines().enumerate() {
+            let text = text.trim_end();
+            if text.is_empty() {
+                if !pattern_lines.is_empty() {
+                    patterns.push(parse_grid_lines(pattern_lines.drain(..))?);
+                }
+            } else {
+                // Line is not empty
+                if pattern_lines.is_empty() {
+                    first_lines.push(idx + 1);
+                }
+                pattern_lines.push(InputLine { number: idx + 1, text });
+            }
+        }
+        if !pattern_lines.is_empty() {
+            patterns.push(parse_grid_lines(pattern_lines)?);
+        }
+        Ok(Input {
+            patterns,
+            first_lines,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    // A line between two columns.
+    Vertical,
+    // A line between two rows.
+    Horizontal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reflection {
+    pub axis: Axis,
+    // Columns left of or rows above the line.
+    pub before: usize,
+    // Columns or rows that have a mirror image, the rest runs off the pattern.
+    pub extent: Range<usize>,
+    // Cells that differ from their mirror image, flipping either cell of a pair fixes it.
+    pub smudges: Vec<(Location, Location)>,
+}
+
+impl Reflection {
+    pub fn score(&self) -> ReturnType {
+        match self.axis {
+            Axis::Vertical => self.before as ReturnType,
+            Axis::Horizontal => 100 * self.before as ReturnType,
+        }
+    }
+}
+
+// Cells on both sides of the line that mirror each other, nearest to the line first.
+fn mirrored_pairs(
+    pattern: &Pattern,
+    axis: Axis,
+    before: usize,
+) -> impl Iterator<Item = (Location, Location)> {
+    let (height, width) = (pattern.len(), pattern[0].len());
+    let (len, across) = match axis {
+        Axis::Vertical => (width, height),
+        Axis::Horizontal => (height, width),
+    };
+    (0..before.min(len - before)).flat_map(move |distance| {
+        (0..across).map(move |idx| {
+            let (a, b) = (before - 1 - distance, before + distance);
+            match axis {
+                Axis::Vertical => (Location { x: a, y: idx }, Location { x: b, y: idx }),
+                Axis::Horizontal => (Location { x: idx, y: a }, Location { x: idx, y: b }),
+            }
+        })
+    })
+}
+
+// Stops as soon as more than `smudges` cells differ.
+fn find_reflection_at(
+    pattern: &Pattern,
+    axis: Axis,
+    before: usize,
+    smudges: usize,
+) -> Option<Reflection> {
+    let tile = |location: &Location| pattern[location.y][location.x];
+    let mut found = Vec::new();
+    for (a, b) in mirrored_pairs(pattern, axis, before) {
+        if tile(&a) != tile(&b) {
+            found.push((a, b));
+            if found.len() > smudges {
+                return None;
+            }
+        }
+    }
+    let len = match axis {
+        Axis::Vertical => pattern[0].len(),
+        Axis::Horizontal => pattern.len(),
+    };
+    let reach = before.min(len - before);
+    (found.len() == smudges).then_some(Reflection {
+        axis,
+        before,
+        extent: before - reach..before + reach,
+        smudges: found,
+    })
+}
+
+// Every line the pattern reflects across with exactly `smudges` cells off, vertical lines
+// first.
+pub fn find_reflections(pattern: &Pattern, smudges: usize) -> Vec<Reflection> {
+    let vertical = (1..pattern[0].len()).map(|before| (Axis::Vertical, before));
+    let horizontal = (1..pattern.len()).map(|before| (Axis::Horizontal, before));
+    vertical
+        .chain(horizontal)
+        .filter_map(|(axis, before)| find_reflection_at(pattern, axis, before, smudges))
+        .collect()
+}
+
+impl Display for Reflection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (line, lanes) = match self.axis {
+            Axis::Vertical => ("vertical", "columns"),
+            Axis::Horizontal => ("horizontal", "rows"),
+        };
+        write!(
+            f,
+            "{} line after {} {}, mirrors {} {} to {}, score {}",
+            line,
+            &lanes[..lanes.len() - 1],
+            self.before,
+            lanes,
+            self.extent.start + 1,
+            self.extent.end,
+            self.score()
+        )?;
+        // Counted from one like the rows and columns above.
+        let cell = |l: &Location| format!("column {} row {}", l.x + 1, l.y + 1);
+        for (a, b) in &self.smudges {
+            write!(f, ", smudge at {} or {}", cell(a), cell(b))?;
+        }
+        Ok(())
+    }
+}
+
+// Every pattern with all of its reflection lines.
+pub fn reflection_report(input: &Input, smudges: usize) -> Vec<Vec<Reflection>> {
+    input
+        .patterns
+        .iter()
+        .map(|pattern| find_reflections(pattern, smudges))
+        .collect()
+}
+
+// Sum of the scores, every pattern needs exactly one line to be summarized.
+fn summarize(input: &Input, smudges: usize) -> Result<ReturnType, Error> {
+    let expected = match smudges {
+        0 => "exactly one mirror per pattern",
+        _ => "exactly one smudged mirror per pattern",
+    };
+    let report = reflection_report(input, smudges);
+    let mut result: ReturnType = 0;
+    for (reflections, line) in report.iter().zip(&input.first_lines) {
+        match &reflections[..] {
+            [reflection] => result += reflection.score(),
+            _ => {
+                let found = format!("{} mirrors", reflections.len());
+                return Err(Error::new(ErrorKind::NoSolution(expected), found).at_line(*line));
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn calculate_part_one(input: &Input) -> Result<ReturnType, Error> {
+    summarize(input, 0)
+}
+
+fn calculate_part_two(input: &Input) -> Result<ReturnType, Error> {
+    summarize(input, 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const USAGE: &str = "Usage: day13 [PATH|-] [--mirrors [SMUDGES]]";
+
+// With `--mirrors` every reflection line with that many smudges, none by default, is listed
+// instead of the answers.
+pub fn run() {
+    let mut smudges = None;
+    let source = input_source_from_args(Day13::DAY, INPUT_PATH, USAGE, |flag, args| {
+        match flag {
+            "--mirrors" => {
+                let count = args.peek().and_then(|value| value.parse().ok());
+                if count.is_some() {
+                    args.next();
+                }
+                smudges = Some(count.unwrap_or(0));
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+        Ok(())
+    })
+    .unwrap_or_else(|err| exit_with_error(&err));
+    let Some(smudges) = smudges else {
+        return aoc_core::run::<Day13>("day13", INPUT_PATH);
+    };
+    let input_str = source.read().unwrap_or_else(|err| exit_with_error(&err));
+    let input = Input::try_from(input_str.as_str())
+        .unwrap_or_else(|err| exit_with_error(&err.in_day(Day13::DAY).to_string()));
+    let report = reflection_report(&input, smudges);
+    for ((idx, reflections), line) in report.iter().enumerate().zip(&input.first_lines) {
+        let count = match reflections.len() {
+            0 => "no mirror".to_string(),
+            1 => "1 mirror".to_string(),
+            n => format!("{} mirrors", n),
+        };
+        println!("Pattern {} (line {}): {}", idx + 1, line, count);
+        for reflection in reflections {
+            println!("  {}", reflection);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(405));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(400));
+    }
+
+    #[test]
+    fn smudges_are_named() {
+        let input = Input::try_from(SAMPLE_INPUT).unwrap();
+        let at = |x, y| Location { x, y };
+        let first = find_reflections(&input.patterns[0], 1);
+        assert_eq!(
+            first,
+            vec![Reflection {
+                axis: Axis::Horizontal,
+                before: 3,
+                extent: 0..6,
+                smudges: vec![(at(0, 0), at(0, 5))],
+            }]
+        );
+        let second = find_reflections(&input.patterns[1], 1);
+        assert_eq!(second[0].smudges, vec![(at(4, 0), at(4, 1))]);
+        assert_eq!(second[0].score(), 100);
+        let clean = find_reflections(&input.patterns[0], 0);
+        assert_eq!((clean[0].axis, clean[0].before), (Axis::Vertical, 5));
+        assert!(clean[0].smudges.is_empty());
+    }
+
+    #[test]
+    fn every_mirror_is_reported() {
+        // Reflects after the third column and after the first row.
+        let input = Input::try_from("\n##..\n##..\n#...\n\n#.\n.#\n").unwrap();
+        let report = reflection_report(&input, 0);
+        let lines: Vec<_> = report[0].iter().map(|r| (r.before, r.extent.clone())).collect();
+        assert_eq!(lines, vec![(3, 2..4), (1, 0..2)]);
+        assert_eq!(
+            report[0][0].to_string(),
+            "vertical line after column 3, mirrors columns 3 to 4, score 3"
+        );
+        assert_eq!(report[0][1].score(), 100);
+        assert!(report[1].is_empty());
+        let err = calculate_part_one(&input).unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (Some(2), "2 mirrors"));
+    }
+}
@@ -0,0 +1,432 @@
+use aoc_core::{
+    exit_with_error, input_source_from_args, parse_grid, Direction, Error, Grid, Location, Solution,
+};
+use std::fmt::Display;
+
+mod platform;
+mod program;
+
+pub use platform::Platform;
+pub use program::TiltProgram;
+
+type ReturnType = u128;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Rounded,
+    Cube,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Tile::Cube),
+            'O' => Ok(Tile::Rounded),
+            '.' => Ok(Tile::Empty),
+            _ => Err(Error::invalid_tile(value)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    platform: Platform,
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.platform)
+    }
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        let grid: Grid<Tile> = parse_grid(input_str)?;
+        Ok(Input {
+            platform: Platform::from(&grid),
+        })
+    }
+}
+
+fn cycle(platform: &mut Platform) {
+    for direction in [
+        Direction::Up,
+        Direction::Left,
+        Direction::Down,
+        Direction::Right,
+    ] {
+        platform.tilt(direction);
+    }
+}
+
+// Load of a rock on the given edge: how many rows or columns away from the opposite edge it is.
+fn rock_load(platform: &Platform, rock: &Location, edge: Direction) -> ReturnType {
+    let load = match edge {
+        Direction::Up => platform.height() - rock.y,
+        Direction::Down => rock.y + 1,
+        Direction::Left => platform.width() - rock.x,
+        Direction::Right => rock.x + 1,
+    };
+    load as ReturnType
+}
+
+pub fn load(platform: &Platform, edge: Direction) -> ReturnType {
+    platform
+        .rounded()
+        .map(|rock| rock_load(platform, &rock, edge))
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadBreakdown {
+    pub edge: Direction,
+    pub rows: Vec<ReturnType>,
+    pub columns: Vec<ReturnType>,
+}
+
+impl LoadBreakdown {
+    pub fn total(&self) -> ReturnType {
+        self.rows.iter().sum()
+    }
+}
+
+pub fn load_breakdown(platform: &Platform, edge: Direction) -> LoadBreakdown {
+    let mut breakdown = LoadBreakdown {
+        edge,
+        rows: vec![0; platform.height()],
+        columns: vec![0; platform.width()],
+    };
+    for rock in platform.rounded() {
+        let load = rock_load(platform, &rock, edge);
+        breakdown.rows[rock.y] += load;
+        breakdown.columns[rock.x] += load;
+    }
+    breakdown
+}
+
+fn get_north_load(platform: &Platform) -> ReturnType {
+    load(platform, Direction::Up)
+}
+
+fn calculate_part_one(input: &Input) -> ReturnType {
+    let mut p = input.platform.clone();
+    p.tilt(Direction::Up);
+    get_north_load(&p)
+}
+
+// States repeat from index `start` on, every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+// First half of Brent's algorithm: the hare runs ahead of the tortoise, which jumps to the
+// hare every power of two steps, until they meet. Gives up with the state of the hare once it
+// has taken `limit` steps.
+fn find_period<T: Clone + Eq>(
+    initial: &T,
+    step: impl Fn(&T) -> T,
+    limit: usize,
+) -> Result<usize, T> {
+    let mut power = 1;
+    let mut period = 1;
+    let mut steps = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if steps >= limit {
+            return Err(hare);
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+    Ok(period)
+}
+
+// A hare `period` steps ahead meets the tortoise where the cycle starts.
+fn find_start<T: Clone + Eq>(initial: &T, step: impl Fn(&T) -> T, period: usize) -> usize {
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+    start
+}
+
+// Brent's algorithm, keeps only two states around.
+fn find_cycle<T: Clone + Eq>(initial: &T, step: impl Fn(&T) -> T) -> Cycle {
+    match find_period(initial, &step, usize::MAX) {
+        Ok(period) => Cycle {
+            start: find_start(initial, &step, period),
+            period,
+        },
+        Err(_) => unreachable!("a state repeats before the hare runs out of steps"),
+    }
+}
+
+// State after n steps, only walking up to the first repeat and once around the cycle.
+fn state_after<T: Clone + Eq>(initial: &T, step: impl Fn(&T) -> T, n: usize) -> T {
+    if n == 0 {
+        return initial.clone();
+    }
+    let period = match find_period(initial, &step, n) {
+        Ok(period) => period,
+        // Reached n before anything repeated.
+        Err(state) => return state,
+    };
+    let start = find_start(initial, &step, period);
+    let steps = if n < start {
+        n
+    } else {
+        start + (n - start) % period
+    };
+    let mut state = initial.clone();
+    for _ in 0..steps {
+        state = step(&state);
+    }
+    state
+}
+
+fn spin(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    cycle(&mut platform);
+    platform
+}
+
+pub fn spin_cycle(input: &Input) -> Cycle {
+    find_cycle(&input.platform, spin)
+}
+
+pub fn run_program(input: &Input, program: &TiltProgram) -> Platform {
+    let mut platform = input.platform.clone();
+    for (tilts, repeat) in program.steps() {
+        let step = |platform: &Platform| {
+            let mut platform = platform.clone();
+            for direction in tilts {
+                platform.tilt(*direction);
+            }
+            platform
+        };
+        platform = state_after(&platform, step, *repeat);
+    }
+    platform
+}
+
+pub fn north_load_after_cycles(input: &Input, cycles: usize) -> ReturnType {
+    get_north_load(&state_after(&input.platform, spin, cycles))
+}
+
+fn calculate_part_two(input: &Input) -> ReturnType {
+    north_load_after_cycles(input, 1_000_000_000)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const USAGE: &str = "Usage: day14 [PATH|-] [--program TILTS] [--edge N|W|S|E] [--breakdown]";
+
+fn edge_name(edge: Direction) -> &'static str {
+    match edge {
+        Direction::Up => "north",
+        Direction::Down => "south",
+        Direction::Left => "west",
+        Direction::Right => "east",
+    }
+}
+
+fn join(loads: &[ReturnType]) -> String {
+    loads
+        .iter()
+        .map(|load| load.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// With any of the options the program is run, no tilts by default, and the load on the edge,
+// north by default, is printed instead of the answers.
+pub fn run() {
+    let mut program = None;
+    let mut edge = None;
+    let mut breakdown = false;
+    let source = input_source_from_args(Day14::DAY, INPUT_PATH, USAGE, |flag, args| {
+        match flag {
+            "--program" => {
+                let value = args.next().ok_or(USAGE)?;
+                let parsed = value.parse::<TiltProgram>();
+                program = Some(parsed.map_err(|err| err.to_string())?);
+            }
+            "--edge" => {
+                let value = args.next().ok_or(USAGE)?;
+                let mut chars = value.chars();
+                edge = match (chars.next().and_then(program::tilt_direction), chars.next()) {
+                    (Some(edge), None) => Some(edge),
+                    _ => return Err(USAGE.to_string()),
+                };
+            }
+            "--breakdown" => breakdown = true,
+            _ => return Err(USAGE.to_string()),
+        }
+        Ok(())
+    })
+    .unwrap_or_else(|err| exit_with_error(&err));
+    if program.is_none() && edge.is_none() && !breakdown {
+        return aoc_core::run::<Day14>("day14", INPUT_PATH);
+    }
+    let input_str = source.read().unwrap_or_else(|err| exit_with_error(&err));
+    let input = Input::try_from(input_str.as_str())
+        .unwrap_or_else(|err| exit_with_error(&err.in_day(Day14::DAY).to_string()));
+    let platform = run_program(&input, &program.unwrap_or_default());
+    let loads = load_breakdown(&platform, edge.unwrap_or(Direction::Up));
+    println!(
+        "Load on the {} edge: {}",
+        edge_name(loads.edge),
+        loads.total()
+    );
+    if breakdown {
+        println!("Rows: {}", join(&loads.rows));
+        println!("Columns: {}", join(&loads.columns));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn spin_cycle_repeats() {
+        let input = Input::try_from(SAMPLE_INPUT).unwrap();
+        assert_eq!(
+            spin_cycle(&input),
+            Cycle {
+                start: 3,
+                period: 7
+            }
+        );
+        let mut platform = input.platform.clone();
+        for cycles in 0..20 {
+            assert_eq!(
+                north_load_after_cycles(&input, cycles),
+                get_north_load(&platform)
+            );
+            cycle(&mut platform);
+        }
+    }
+
+    #[test]
+    fn platform_tilts_in_every_direction() {
+        let mut platform = Input::try_from(SAMPLE_INPUT).unwrap().platform;
+        cycle(&mut platform);
+        let expected = "
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+";
+        assert_eq!(platform, Input::try_from(expected).unwrap().platform);
+        assert_eq!(format!("\n{}", platform), expected);
+    }
+
+    #[test]
+    fn tilts_cross_word_boundaries() {
+        // Wider and taller than a 64 bit word, with the rock ending up in the far corner.
+        let row = |rock: usize| format!("{}O{}", ".".repeat(rock), ".".repeat(129 - rock));
+        let mut lines = vec![".".repeat(130); 70];
+        lines[3] = row(100);
+        let mut platform = Input::try_from(lines.join("\n").as_str()).unwrap().platform;
+        platform.tilt(Direction::Right);
+        platform.tilt(Direction::Down);
+        lines[3] = ".".repeat(130);
+        lines[69] = row(129);
+        assert_eq!(platform.to_string(), lines.join("\n") + "\n");
+        assert_eq!(get_north_load(&platform), 1);
+    }
+
+    #[test]
+    fn tilt_programs_and_loads() {
+        let input = Input::try_from(SAMPLE_INPUT).unwrap();
+        let run = |program: &str| run_program(&input, &program.parse().unwrap());
+        assert_eq!(get_north_load(&run("NWSE*1000000000")), 64);
+        assert_eq!(run("N"), run("N N*5 S N"));
+        assert_eq!(load(&run("NWSE NWSE NWSE"), Direction::Up), 69);
+
+        let breakdown = load_breakdown(&run("N"), Direction::Up);
+        assert_eq!(breakdown.total(), 136);
+        assert_eq!(breakdown.columns.iter().sum::<ReturnType>(), 136);
+        assert_eq!(breakdown.rows[0], 50);
+        let west = load_breakdown(&run("W"), Direction::Left);
+        assert_eq!(west.rows[0], 10);
+        assert_eq!(west.total(), load(&run("W"), Direction::Left));
+
+        assert!("NX".parse::<TiltProgram>().is_err());
+        assert!("*3".parse::<TiltProgram>().is_err());
+        assert!("N*".parse::<TiltProgram>().is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::rc::Rc;
+
+use aoc_core::{Direction, Grid, Location};
+
+use crate::Tile;
+
+// One bitset per line, all lines in a single buffer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Lines {
+    // Words per line.
+    stride: usize,
+    words: Vec<u64>,
+}
+
+impl Lines {
+    fn new(lines: usize, len: usize) -> Self {
+        let stride = len.div_ceil(64);
+        Lines {
+            stride,
+            words: vec![0; lines * stride],
+        }
+    }
+
+    fn contains(&self, line: usize, idx: usize) -> bool {
+        self.words[line * self.stride + idx / 64] & (1 << (idx % 64)) != 0
+    }
+
+    fn insert(&mut self, line: usize, idx: usize) {
+        self.words[line * self.stride + idx / 64] |= 1 << (idx % 64);
+    }
+
+    // Word index and mask of every word the range touches.
+    fn words(&self, line: usize, range: Range<usize>) -> impl Iterator<Item = (usize, u64)> {
+        let base = line * self.stride;
+        let Range { start, end } = range;
+        let (first, last) = (start / 64, end.saturating_sub(1) / 64);
+        let words = first..if start < end { last + 1 } else { first };
+        words.map(move |word| {
+            let mut mask = u64::MAX;
+            if word == first {
+                mask &= u64::MAX << (start % 64);
+            }
+            if word == last {
+                mask &= u64::MAX >> (63 - (end - 1) % 64);
+            }
+            (base + word, mask)
+        })
+    }
+
+    fn count(&self, line: usize, range: Range<usize>) -> usize {
+        self.words(line, range)
+            .map(|(word, mask)| (self.words[word] & mask).count_ones() as usize)
+            .sum()
+    }
+
+    fn insert_range(&mut self, line: usize, range: Range<usize>) {
+        for (word, mask) in self.words(line, range) {
+            self.words[word] |= mask;
+        }
+    }
+
+    // Bit j of line i becomes bit i of line j, in blocks of 64 by 64 bits.
+    fn transpose(&self, lines: usize, len: usize) -> Lines {
+        let mut result = Lines::new(len, lines);
+        for block_row in 0..lines.div_ceil(64) {
+            for block_column in 0..self.stride {
+                let mut block = [0u64; 64];
+                for (i, word) in block.iter_mut().enumerate() {
+                    let line = block_row * 64 + i;
+                    if line < lines {
+                        *word = self.words[line * self.stride + block_column];
+                    }
+                }
+                transpose_block(&mut block);
+                for (i, word) in block.iter().enumerate() {
+                    let line = block_column * 64 + i;
+                    if line < len {
+                        result.words[line * result.stride + block_row] = *word;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+// Swaps ever smaller off diagonal quarters, bit j of word i ends up as bit i of word j.
+fn transpose_block(block: &mut [u64; 64]) {
+    let mut width = 32;
+    let mut mask: u64 = 0x0000_0000_ffff_ffff;
+    while width != 0 {
+        let mut k = 0;
+        while k < 64 {
+            let swap = ((block[k] >> width) ^ block[k + width]) & mask;
+            block[k] ^= swap << width;
+            block[k + width] ^= swap;
+            k = (k + width + 1) & !width;
+        }
+        width >>= 1;
+        mask ^= mask << width;
+    }
+}
+
+// Runs of tiles between cubes in every row and column. Cubes never move, so they are only
+// worked out once per input.
+#[derive(Debug)]
+struct Runs {
+    rows: Vec<Vec<Range<usize>>>,
+    columns: Vec<Vec<Range<usize>>>,
+}
+
+fn runs(cubes: impl Iterator<Item = usize>, len: usize) -> Vec<Range<usize>> {
+    let mut start = 0;
+    let mut runs = Vec::new();
+    for end in cubes.chain(std::iter::once(len)) {
+        if start < end {
+            runs.push(start..end);
+        }
+        start = end + 1;
+    }
+    runs
+}
+
+// Rounded rocks as a bitset per row and one per column, a tilt fills each run between two
+// cubes in one go. Both views always hold the same rocks.
+#[derive(Debug, Clone)]
+pub struct Platform {
+    width: usize,
+    height: usize,
+    rounded_rows: Lines,
+    rounded_columns: Lines,
+    cubes: Lines,
+    runs: Rc<Runs>,
+}
+
+impl From<&Grid<Tile>> for Platform {
+    fn from(grid: &Grid<Tile>) -> Self {
+        let height = grid.len();
+        let width = grid[0].len();
+        let mut rounded_rows = Lines::new(height, width);
+        let mut rounded_columns = Lines::new(width, height);
+        let mut cubes = Lines::new(height, width);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                match tile {
+                    Tile::Rounded => {
+                        rounded_rows.insert(y, x);
+                        rounded_columns.insert(x, y);
+                    }
+                    Tile::Cube => cubes.insert(y, x),
+                    Tile::Empty => {}
+                }
+            }
+        }
+        let row_cubes = |y: usize| (0..width).filter(move |x| grid[y][*x] == Tile::Cube);
+        let column_cubes = |x: usize| (0..height).filter(move |y| grid[*y][x] == Tile::Cube);
+        let runs = Runs {
+            rows: (0..height).map(|y| runs(row_cubes(y), width)).collect(),
+            columns: (0..width).map(|x| runs(column_cubes(x), height)).collect(),
+        };
+        Platform {
+            width,
+            height,
+            rounded_rows,
+            rounded_columns,
+            cubes,
+            runs: Rc::new(runs),
+        }
+    }
+}
+
+impl Platform {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn tile(&self, x: usize, y: usize) -> Tile {
+        if self.rounded_rows.contains(y, x) {
+            Tile::Rounded
+        } else if self.cubes.contains(y, x) {
+            Tile::Cube
+        } else {
+            Tile::Empty
+        }
+    }
+
+    // Rounded rocks, row by row.
+    pub fn rounded(&self) -> impl Iterator<Item = Location> + '_ {
+        let stride = self.rounded_rows.stride;
+        self.rounded_rows
+            .words
+            .iter()
+            .enumerate()
+            .flat_map(move |(idx, word)| {
+                let mut word = *word;
+                std::iter::from_fn(move || {
+                    (word != 0).then(|| {
+                        let bit = word.trailing_zeros() as usize;
+                        word &= word - 1;
+                        Location {
+                            x: idx % stride * 64 + bit,
+                            y: idx / stride,
+                        }
+                    })
+                })
+            })
+    }
+
+    // Rolls every rounded rock as far as it goes, one pass over the runs between cubes.
+    pub fn tilt(&mut self, direction: Direction) {
+        let along_rows = matches!(direction, Direction::Left | Direction::Right);
+        let towards_start = matches!(direction, Direction::Up | Direction::Left);
+        let (rounded, runs, len, count) = if along_rows {
+            (&self.rounded_rows, &self.runs.rows, self.width, self.height)
+        } else {
+            (
+                &self.rounded_columns,
+                &self.runs.columns,
+                self.height,
+                self.width,
+            )
+        };
+        let mut lines = Lines::new(count, len);
+        for (line, runs) in runs.iter().enumerate() {
+            for run in runs {
+                let rocks = rounded.count(line, run.clone());
+                let filled = if towards_start {
+                    run.start..run.start + rocks
+                } else {
+                    run.end - rocks..run.end
+                };
+                lines.insert_range(line, filled);
+            }
+        }
+        let crossing = lines.transpose(count, len);
+        if along_rows {
+            self.rounded_rows = lines;
+            self.rounded_columns = crossing;
+        } else {
+            self.rounded_columns = lines;
+            self.rounded_rows = crossing;
+        }
+    }
+}
+
+impl PartialEq for Platform {
+    fn eq(&self, other: &Self) -> bool {
+        self.rounded_rows == other.rounded_rows && self.cubes == other.cubes
+    }
+}
+
+impl Eq for Platform {}
+
+// Cubes never move, the rounded rocks tell platforms of the same input apart.
+impl Hash for Platform {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rounded_rows.hash(state);
+    }
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let symbol = match self.tile(x, y) {
+                    Tile::Cube => '#',
+                    Tile::Rounded => 'O',
+                    Tile::Empty => '.',
+                };
+                write!(f, "{}", symbol)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
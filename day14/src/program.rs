@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use aoc_core::{Direction, Error, ErrorKind};
+
+// Tilts named by the edge the rocks roll towards.
+pub fn tilt_direction(name: char) -> Option<Direction> {
+    match name {
+        'N' => Some(Direction::Up),
+        'S' => Some(Direction::Down),
+        'W' => Some(Direction::Left),
+        'E' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+// Groups of tilts, each run a number of times: `NWSE*1000000000` is the spin cycle a billion
+// times, `N E N` tilts north, east and north again.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TiltProgram {
+    steps: Vec<(Vec<Direction>, usize)>,
+}
+
+impl TiltProgram {
+    pub fn steps(&self) -> &[(Vec<Direction>, usize)] {
+        &self.steps
+    }
+}
+
+impl FromStr for TiltProgram {
+    type Err = Error;
+
+    fn from_str(program_str: &str) -> Result<Self, Self::Err> {
+        let malformed = |part: &str| {
+            Error::new(
+                ErrorKind::Malformed(
+                    "tilts N, W, S or E, each group optionally followed by *count",
+                ),
+                part,
+            )
+        };
+        let mut steps = Vec::new();
+        let mut tilts = Vec::new();
+        let mut rest = program_str.trim_start();
+        while let Some(c) = rest.chars().next() {
+            if let Some(direction) = tilt_direction(c) {
+                tilts.push(direction);
+                rest = &rest[1..];
+            } else if let Some(count) = rest.strip_prefix('*') {
+                let digits = count
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(count.len());
+                let repeat = count[..digits].parse().map_err(|_| malformed(rest))?;
+                if tilts.is_empty() {
+                    return Err(malformed(rest));
+                }
+                steps.push((std::mem::take(&mut tilts), repeat));
+                rest = &count[digits..];
+            } else if c.is_whitespace() {
+                if !tilts.is_empty() {
+                    steps.push((std::mem::take(&mut tilts), 1));
+                }
+                rest = rest.trim_start();
+            } else {
+                return Err(malformed(rest));
+            }
+        }
+        if !tilts.is_empty() {
+            steps.push((tilts, 1));
+        }
+        Ok(TiltProgram { steps })
+    }
+}
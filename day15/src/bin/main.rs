@@ -0,0 +1,3 @@
+fn main() {
+    day15::run()
+}
@@ -1,3 +1,4 @@
+use aoc_core::{numbered_lines, Error, ErrorKind, InputLine, Solution};
 use std::fmt::Display;
 
 type ReturnType = u128;
@@ -30,53 +31,47 @@ impl Display for Operation {
     }
 }
 
-impl TryFrom<&str> for Operation {
-    type Error = &'static str;
-
-    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
-        let input_chars: Vec<char> = input_str.chars().collect();
-        let label: String = input_chars
-            .iter()
-            .take_while(|c| !['=', '-'].contains(c))
-            .collect();
-        let operation = match &input_str[label.len()..label.len() + 1] {
-            "-" => Operation::Remove(label),
-            "=" => {
-                let focal_length = input_str[label.len() + 1..]
-                    .parse()
-                    .map_err(|_| "Cannot parse focal length")?;
-                Operation::Add(Lens {
-                    label,
-                    focal_length,
-                })
-            }
-            &_ => panic!("Cannot parse operation"),
-        };
-        dbg!(&input_str, &operation);
-        Ok(operation)
-    }
+fn parse_operation(line: &InputLine, step: &str) -> Result<Operation, Error> {
+    let expected = ErrorKind::Malformed("`<label>-` or `<label>=<focal length>`");
+    let op_idx = step
+        .find(['=', '-'])
+        .ok_or_else(|| line.error(expected, step))?;
+    let label = step[..op_idx].to_string();
+    let operation = match &step[op_idx..] {
+        "-" => Operation::Remove(label),
+        rest => match rest.strip_prefix('=') {
+            Some(focal_length_str) => Operation::Add(Lens {
+                label,
+                focal_length: line.parse(focal_length_str)?,
+            }),
+            None => return Err(line.error(expected, step)),
+        },
+    };
+    Ok(operation)
 }
 
 #[derive(Debug)]
-struct Input {
+pub struct Input {
+    words: Vec<String>,
     operations: Vec<Operation>,
 }
 
 impl TryFrom<&str> for Input {
-    type Error = &'static str;
+    type Error = Error;
 
     fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        let mut words = Vec::new();
         let mut operations = Vec::new();
-        for line in input_str.lines() {
-            if !line.is_empty() {
-                let line_ops = line
-                    .split(",")
-                    .map(Operation::try_from)
-                    .collect::<Result<Vec<Operation>, Self::Error>>()?;
-                operations.extend(line_ops);
-            }
+        for line in numbered_lines(input_str) {
+            words.extend(line.text.split(",").map(String::from));
+            let line_ops = line
+                .text
+                .split(",")
+                .map(|step| parse_operation(&line, step))
+                .collect::<Result<Vec<Operation>, Self::Error>>()?;
+            operations.extend(line_ops);
         }
-        Ok(Input { operations })
+        Ok(Input { words, operations })
     }
 }
 
@@ -93,7 +88,7 @@ impl Display for Input {
     }
 }
 
-fn hash_word(word: &String) -> usize {
+fn hash_word(word: &str) -> usize {
     let mut result = 0;
     for c in word.chars() {
         // Determine the ASCII code for the current character of the string.
@@ -105,7 +100,6 @@ fn hash_word(word: &String) -> usize {
         // Set the current value to the remainder of dividing itself by 256.
         result %= 256;
     }
-    println!("{} => {}", word, result);
     result
 }
 
@@ -139,9 +133,8 @@ impl From<&Input> for Storage {
         for op in &input.operations {
             match &op {
                 Operation::Remove(label) => {
-                    let box_idx = hash_word(&label);
+                    let box_idx = hash_word(label);
                     boxes[box_idx].retain_mut(|lens| lens.label != *label);
-                    dbg!(op, box_idx);
                 }
                 Operation::Add(lens) => {
                     let box_idx = hash_word(&lens.label);
@@ -149,36 +142,73 @@ impl From<&Input> for Storage {
                         Some(lens_idx) => boxes[box_idx][lens_idx] = lens.clone(),
                         None => boxes[box_idx].push(lens.clone()),
                     }
-
-                    dbg!(op, box_idx);
                 }
             }
         }
         Self { boxes }
     }
 }
-fn calculate(input: &Input) -> ReturnType {
-    println!("{}", input);
+
+fn calculate_part_one(input: &Input) -> ReturnType {
+    input
+        .words
+        .iter()
+        .map(|word| hash_word(word) as ReturnType)
+        .sum()
+}
+
+fn calculate_part_two(input: &Input) -> ReturnType {
     let storage = Storage::from(input);
-    dbg!(&storage);
     storage.calculate()
 }
 
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
 pub fn run() {
-    let input_str = include_str!("../input.txt");
-    let mut input = Input::try_from(input_str).unwrap();
-    let result = calculate(&mut input);
-    println!("Result for day13a: {}", result);
+    aoc_core::run::<Day15>("day15", INPUT_PATH);
 }
-// 42361
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const SAMPLE_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 1320);
+    }
+
     #[test]
-    fn it_works() {
-        let sample_input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        let result = calculate(&mut Input::try_from(sample_input).unwrap());
+    fn part_two_works() {
+        let result = calculate_part_two(&Input::try_from(SAMPLE_INPUT).unwrap());
         assert_eq!(result, 145);
     }
+
+    #[test]
+    fn reports_bad_step() {
+        let err = Input::try_from("rn=1,cm+2").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), Some(6)));
+    }
 }
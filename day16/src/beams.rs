@@ -0,0 +1,202 @@
+use aoc_core::{boundary_rays, Direction};
+
+use crate::optics::direction_index;
+use crate::{entering, Input, Light};
+
+// Tiles as bits, indexed by y * width + x.
+#[derive(Debug, Clone)]
+struct TileSet(Vec<u64>);
+
+impl TileSet {
+    fn new(tiles: usize) -> Self {
+        TileSet(vec![0; tiles.div_ceil(64)])
+    }
+
+    fn insert(&mut self, tile: usize) {
+        self.0[tile / 64] |= 1 << (tile % 64);
+    }
+
+    fn union(&mut self, other: &TileSet) {
+        for (word, other) in self.0.iter_mut().zip(&other.0) {
+            *word |= other;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+}
+
+const UNVISITED: usize = usize::MAX;
+
+// Every (tile, direction) a beam can be in, condensed into strongly connected components.
+// Beams entering from different edges quickly run into the same components, so the tiles
+// energized from a component are worked out once and shared.
+pub struct BeamGraph {
+    width: usize,
+    component: Vec<usize>,
+    // Per component: the tiles of its own states and the components it leads to.
+    tiles: Vec<Vec<usize>>,
+    successors: Vec<Vec<usize>>,
+    // Only kept for components that branch or loop and can be reached from the edge, straight
+    // runs are followed instead.
+    energized: Vec<Option<TileSet>>,
+}
+
+fn state(width: usize, light: &Light) -> usize {
+    (light.y as usize * width + light.x as usize) * 4 + direction_index(light.direction)
+}
+
+impl BeamGraph {
+    pub fn new(input: &Input) -> Self {
+        let height = input.grid.len();
+        let width = input.grid[0].len();
+        let states = width * height * 4;
+
+        // Edges of every state, packed: the successors of s are edges[starts[s]..starts[s + 1]].
+        let mut starts = Vec::with_capacity(states + 1);
+        let mut edges = Vec::with_capacity(states);
+        for y in 0..height {
+            for x in 0..width {
+                for direction in Direction::ALL {
+                    starts.push(edges.len());
+                    let light = Light {
+                        direction,
+                        x: x as i32,
+                        y: y as i32,
+                    };
+                    edges.extend(light.run(input).iter().map(|next| state(width, next)));
+                }
+            }
+        }
+        starts.push(edges.len());
+
+        // Tarjan, with an explicit stack. Components are numbered sinks first.
+        let mut index = vec![UNVISITED; states];
+        let mut lowlink = vec![0; states];
+        let mut on_stack = vec![false; states];
+        let mut stack = Vec::new();
+        let mut calls: Vec<(usize, usize)> = Vec::new();
+        let mut component = vec![UNVISITED; states];
+        let mut components = 0;
+        let mut counter = 0;
+        for root in 0..states {
+            if index[root] != UNVISITED {
+                continue;
+            }
+            calls.push((root, 0));
+            while let Some(&(v, edge)) = calls.last() {
+                if index[v] == UNVISITED {
+                    index[v] = counter;
+                    lowlink[v] = counter;
+                    counter += 1;
+                    stack.push(v);
+                    on_stack[v] = true;
+                    calls.last_mut().unwrap().1 = starts[v];
+                    continue;
+                }
+                if edge < starts[v + 1] {
+                    calls.last_mut().unwrap().1 += 1;
+                    let w = edges[edge];
+                    if index[w] == UNVISITED {
+                        calls.push((w, 0));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w]);
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some(&(u, _)) = calls.last() {
+                    lowlink[u] = lowlink[u].min(lowlink[v]);
+                }
+                if lowlink[v] == index[v] {
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        component[w] = components;
+                        if w == v {
+                            break;
+                        }
+                    }
+                    components += 1;
+                }
+            }
+        }
+
+        let mut tiles = vec![Vec::new(); components];
+        let mut successors = vec![Vec::new(); components];
+        for s in 0..states {
+            let c = component[s];
+            tiles[c].push(s / 4);
+            for &edge in &edges[starts[s]..starts[s + 1]] {
+                if component[edge] != c {
+                    successors[c].push(component[edge]);
+                }
+            }
+        }
+        for (tiles, successors) in tiles.iter_mut().zip(&mut successors) {
+            tiles.dedup();
+            successors.sort_unstable();
+            successors.dedup();
+        }
+
+        // Only components a beam from the edge runs into are ever asked for.
+        let mut reachable = vec![false; components];
+        let mut pending: Vec<usize> = boundary_rays(&input.grid)
+            .map(|(location, direction)| component[state(width, &entering(location, direction))])
+            .collect();
+        while let Some(c) = pending.pop() {
+            if !reachable[c] {
+                reachable[c] = true;
+                pending.extend(&successors[c]);
+            }
+        }
+
+        let mut graph = BeamGraph {
+            width,
+            component,
+            tiles,
+            successors,
+            energized: vec![None; components],
+        };
+        // Successors have lower numbers, so they are done before the components leading to them.
+        for c in (0..components).filter(|c| reachable[*c]) {
+            if graph.successors[c].len() > 1 || graph.tiles[c].len() > 1 {
+                let mut energized = TileSet::new(width * height);
+                graph.collect(c, &mut energized);
+                graph.energized[c] = Some(energized);
+            }
+        }
+        graph
+    }
+
+    // Adds the tiles energized from component c, following straight runs until a component
+    // that already knows its tiles.
+    fn collect(&self, mut c: usize, energized: &mut TileSet) {
+        loop {
+            if let Some(known) = &self.energized[c] {
+                energized.union(known);
+                return;
+            }
+            for tile in &self.tiles[c] {
+                energized.insert(*tile);
+            }
+            match self.successors[c][..] {
+                [] => return,
+                [next] => c = next,
+                _ => {
+                    for next in &self.successors[c] {
+                        self.collect(*next, energized);
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    // Tiles energized by a beam entering the grid from its edge as `light`.
+    pub fn energized(&self, input: &Input, light: &Light) -> usize {
+        let mut energized = TileSet::new(input.grid.len() * self.width);
+        self.collect(self.component[state(self.width, light)], &mut energized);
+        energized.len()
+    }
+}
@@ -0,0 +1,325 @@
+use aoc_core::{
+    boundary_rays, exit_with_error, input_source_from_args, is_inbound, numbered_lines, parse_grid,
+    Direction, Error, ErrorKind, Grid, InputSource, Location, Solution,
+};
+use std::fmt::Display;
+
+use std::collections::{HashMap, HashSet};
+
+mod beams;
+mod optics;
+
+use beams::BeamGraph;
+pub use optics::{Legend, Optic};
+
+type ReturnType = u128;
+
+// Tile symbol, only looked up in the legend once the whole grid is read.
+struct Symbol(char);
+
+impl TryFrom<char> for Symbol {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(Symbol(value))
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    // Index of each tile in the legend.
+    grid: Grid<usize>,
+    legend: Legend,
+    // Each teleporter and the other one of its pair.
+    partners: HashMap<Location, Location>,
+}
+
+impl Input {
+    fn is_inbound(&self, x: i32, y: i32) -> bool {
+        is_inbound(&self.grid, x, y)
+    }
+
+    fn optic(&self, x: i32, y: i32) -> &Optic {
+        self.legend.optic(self.grid[y as usize][x as usize])
+    }
+
+    // Where beams leave the tile from, the other end of the pair for teleporters.
+    fn departure(&self, x: i32, y: i32) -> (i32, i32) {
+        let location = Location {
+            x: x as usize,
+            y: y as usize,
+        };
+        match self.partners.get(&location) {
+            Some(partner) => (partner.x as i32, partner.y as i32),
+            None => (x, y),
+        }
+    }
+
+    pub fn with_legend(input_str: &str, legend: Legend) -> Result<Self, Error> {
+        let symbols: Grid<Symbol> = parse_grid(input_str)?;
+        let mut grid = Vec::new();
+        let mut teleporters: HashMap<char, Vec<Location>> = HashMap::new();
+        for (y, (line, row)) in numbered_lines(input_str).zip(&symbols).enumerate() {
+            let mut tiles = Vec::new();
+            for (x, Symbol(symbol)) in row.iter().enumerate() {
+                let idx = legend
+                    .index(*symbol)
+                    .ok_or_else(|| Error::invalid_tile(*symbol).at(line.number, x + 1))?;
+                if legend.optic(idx).teleport {
+                    teleporters.entry(*symbol).or_default().push(Location { x, y });
+                }
+                tiles.push(idx);
+            }
+            grid.push(tiles);
+        }
+        let mut partners = HashMap::new();
+        for (symbol, locations) in teleporters {
+            let [a, b] = locations[..] else {
+                let found = format!("{} `{}` tiles", locations.len(), symbol);
+                return Err(Error::new(ErrorKind::Malformed("teleporters in pairs"), found));
+            };
+            partners.insert(a, b);
+            partners.insert(b, a);
+        }
+        Ok(Input {
+            grid,
+            legend,
+            partners,
+        })
+    }
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        Input::with_legend(input_str, Legend::standard())
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Grid")?;
+        for line in &self.grid {
+            for idx in line {
+                write!(f, "{}", self.legend.optic(*idx).symbol)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+// Beam entering the tile at x, y.
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+struct Light {
+    direction: Direction,
+    x: i32,
+    y: i32,
+}
+impl Light {
+    fn run(&self, input: &Input) -> Vec<Light> {
+        let (x, y) = input.departure(self.x, self.y);
+        input
+            .optic(self.x, self.y)
+            .outputs(self.direction)
+            .iter()
+            .filter_map(|&direction| {
+                let (dx, dy) = direction.offset();
+                let (next_x, next_y) = (x + dx, y + dy);
+                input.is_inbound(next_x, next_y).then_some(Light {
+                    direction,
+                    x: next_x,
+                    y: next_y,
+                })
+            })
+            .collect()
+    }
+}
+
+fn calculate_light(input: &Input, light: Light) -> ReturnType {
+    let mut seen: HashSet<Light> = HashSet::from([light.clone()]);
+    let mut lights = vec![light];
+    while !lights.is_empty() {
+        let mut new_lights = Vec::new();
+        for light in &lights {
+            let light_result: Vec<Light> = light.run(input).into_iter().filter(|light|!seen.contains(light)).collect();
+            seen.extend(light_result.clone());
+            new_lights.extend(light_result);
+        }
+        lights = new_lights;
+    }
+    seen.into_iter().map(|light|(light.x, light.y)).collect::<HashSet<(i32, i32)>>().len() as ReturnType
+}
+
+fn entering(location: Location, direction: Direction) -> Light {
+    Light {
+        direction,
+        x: location.x as i32,
+        y: location.y as i32,
+    }
+}
+
+fn calculate_part_one(input: &Input) -> ReturnType {
+    calculate_light(input, entering(Location { x: 0, y: 0 }, Direction::Right))
+}
+
+fn calculate_part_two(input: &Input) -> ReturnType {
+    let graph = BeamGraph::new(input);
+    boundary_rays(&input.grid)
+        .map(|(location, direction)| {
+            graph.energized(input, &entering(location, direction)) as ReturnType
+        })
+        .max()
+        .unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        Ok(calculate_part_two(input))
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const USAGE: &str = "Usage: day16 [PATH|-] [--legend LEGEND_PATH]";
+
+// With `--legend` the grid may use the extra tiles described in the legend file.
+pub fn run() {
+    let mut legend_path = None;
+    let source = input_source_from_args(Day16::DAY, INPUT_PATH, USAGE, |flag, args| {
+        match flag {
+            "--legend" => legend_path = Some(args.next().ok_or(USAGE)?),
+            _ => return Err(USAGE.to_string()),
+        }
+        Ok(())
+    })
+    .unwrap_or_else(|err| exit_with_error(&err));
+    let Some(legend_path) = legend_path else {
+        return aoc_core::run::<Day16>("day16", INPUT_PATH);
+    };
+    let fail = |err: Error| -> ! { exit_with_error(&err.in_day(Day16::DAY).to_string()) };
+    let legend_str = InputSource::from_arg(&legend_path)
+        .read()
+        .unwrap_or_else(|err| exit_with_error(&err));
+    let legend = Legend::try_from(legend_str.as_str()).unwrap_or_else(|err| fail(err));
+    let input_str = source.read().unwrap_or_else(|err| exit_with_error(&err));
+    let input = Input::with_legend(&input_str, legend).unwrap_or_else(|err| fail(err));
+    println!("Result for day16 part one: {}", calculate_part_one(&input));
+    println!("Result for day16 part two: {}", calculate_part_two(&input));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+.|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn custom_legend_adds_tiles() {
+        let legend = Legend::try_from(
+            "#\n> >:>\n+ >:>^v <:<^v ^:^<> v:v<>\nT teleport ^:^ v:v <:< >:>\n",
+        )
+        .unwrap();
+        let input = Input::with_legend(".>+T.\n.....\nT#...\n", legend.clone()).unwrap();
+        // Splits down and on into the teleporter, which sends it into the absorber.
+        assert_eq!(calculate_part_one(&input), 7);
+        let graph = BeamGraph::new(&input);
+        for (location, direction) in boundary_rays(&input.grid) {
+            let light = entering(location, direction);
+            let expected = calculate_light(&input, light.clone());
+            assert_eq!(graph.energized(&input, &light) as ReturnType, expected);
+        }
+
+        assert!(Input::with_legend("T..\n", legend.clone()).is_err());
+        assert!(Input::try_from("#..\n").is_err());
+        assert!(Legend::try_from("+ >:x\n").is_err());
+    }
+
+    #[test]
+    fn part_two_enters_every_row_of_tall_grids() {
+        // Only the beam entering the fourth row from the left splits up the column.
+        let result = calculate_part_two(&Input::try_from("..\n..\n..\n.|\n..\n").unwrap());
+        assert_eq!(result, 6);
+    }
+
+    // Pseudo random grid with about one mirror or splitter in ten tiles.
+    fn generated_input(size: usize) -> Input {
+        let mut seed: u64 = 16;
+        let mut grid_str = String::new();
+        for _ in 0..size {
+            for _ in 0..size {
+                seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                grid_str.push(match (seed >> 33) % 40 {
+                    0 => '|',
+                    1 => '-',
+                    2 => '\\',
+                    3 => '/',
+                    _ => '.',
+                });
+            }
+            grid_str.push('\n');
+        }
+        Input::try_from(grid_str.as_str()).unwrap()
+    }
+
+    #[test]
+    fn shared_components_match_simulation() {
+        let input = generated_input(40);
+        let graph = BeamGraph::new(&input);
+        for (location, direction) in boundary_rays(&input.grid) {
+            let light = entering(location, direction);
+            assert_eq!(
+                graph.energized(&input, &light) as ReturnType,
+                calculate_light(&input, light)
+            );
+        }
+    }
+
+    // Slow in debug builds, run with `cargo test --release -p day16 -- --ignored`.
+    #[test]
+    #[ignore]
+    fn part_two_scales_to_large_grids() {
+        let large = generated_input(500);
+        let part_two = calculate_part_two(&large);
+        assert_eq!(part_two, 203798);
+        assert!(part_two > calculate_part_one(&large));
+    }
+}
@@ -0,0 +1,152 @@
+use aoc_core::{numbered_lines, Direction, Error, ErrorKind};
+
+// What a tile does to the beams entering it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optic {
+    pub symbol: char,
+    // Outgoing directions for each incoming one, indexed like `Direction::ALL`. Nothing leaves
+    // an absorber.
+    outputs: [Vec<Direction>; 4],
+    // Beams leave from the other tile with the same symbol, teleporters come in pairs.
+    pub teleport: bool,
+}
+
+pub fn direction_index(direction: Direction) -> usize {
+    match direction {
+        Direction::Up => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Right => 3,
+    }
+}
+
+impl Optic {
+    // Absorbs every beam until routes are added.
+    pub fn new(symbol: char) -> Self {
+        Optic {
+            symbol,
+            outputs: Default::default(),
+            teleport: false,
+        }
+    }
+
+    pub fn route(mut self, incoming: Direction, outgoing: &[Direction]) -> Self {
+        self.outputs[direction_index(incoming)] = outgoing.to_vec();
+        self
+    }
+
+    pub fn teleporting(mut self) -> Self {
+        self.teleport = true;
+        self
+    }
+
+    pub fn outputs(&self, incoming: Direction) -> &[Direction] {
+        &self.outputs[direction_index(incoming)]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Legend {
+    optics: Vec<Optic>,
+}
+
+impl Legend {
+    // The tiles of the puzzle: empty space, splitters and mirrors.
+    pub fn standard() -> Self {
+        use Direction::*;
+        let mut empty = Optic::new('.');
+        for direction in Direction::ALL {
+            empty = empty.route(direction, &[direction]);
+        }
+        let optics = vec![
+            empty,
+            Optic::new('|')
+                .route(Up, &[Up])
+                .route(Down, &[Down])
+                .route(Left, &[Up, Down])
+                .route(Right, &[Up, Down]),
+            Optic::new('-')
+                .route(Up, &[Left, Right])
+                .route(Down, &[Left, Right])
+                .route(Left, &[Left])
+                .route(Right, &[Right]),
+            Optic::new('\\')
+                .route(Up, &[Left])
+                .route(Down, &[Right])
+                .route(Left, &[Up])
+                .route(Right, &[Down]),
+            Optic::new('/')
+                .route(Up, &[Right])
+                .route(Down, &[Left])
+                .route(Left, &[Down])
+                .route(Right, &[Up]),
+        ];
+        Legend { optics }
+    }
+
+    // Adds a tile, or replaces the one with the same symbol.
+    pub fn insert(&mut self, optic: Optic) {
+        match self.index(optic.symbol) {
+            Some(idx) => self.optics[idx] = optic,
+            None => self.optics.push(optic),
+        }
+    }
+
+    pub fn index(&self, symbol: char) -> Option<usize> {
+        self.optics.iter().position(|optic| optic.symbol == symbol)
+    }
+
+    pub fn optic(&self, idx: usize) -> &Optic {
+        &self.optics[idx]
+    }
+}
+
+fn arrow(c: char) -> Option<Direction> {
+    Direction::ALL
+        .into_iter()
+        .find(|direction| direction.to_string() == c.to_string())
+}
+
+// Extra tiles on top of the standard ones, one per line: the symbol, `teleport` for
+// teleporters, then `in:out` pairs of arrows, e.g. `+ >:>^v <:<^v`. Unlisted directions are
+// absorbed.
+impl TryFrom<&str> for Legend {
+    type Error = Error;
+
+    fn try_from(legend_str: &str) -> Result<Self, Self::Error> {
+        let mut legend = Legend::standard();
+        for line in numbered_lines(legend_str) {
+            let mut parts = line.text.split_whitespace();
+            let symbol = parts.next().unwrap_or_default();
+            let mut chars = symbol.chars();
+            let mut optic = match (chars.next(), chars.next()) {
+                (Some(c), None) => Optic::new(c),
+                _ => {
+                    return Err(line.error(ErrorKind::Malformed("a single character tile"), symbol))
+                }
+            };
+            for part in parts {
+                if part == "teleport" {
+                    optic = optic.teleporting();
+                    continue;
+                }
+                let route = part.split_once(':').and_then(|(incoming, outgoing)| {
+                    let mut incoming = incoming.chars();
+                    let direction =
+                        arrow(incoming.next()?).filter(|_| incoming.next().is_none())?;
+                    let outgoing = outgoing.chars().map(arrow).collect::<Option<Vec<_>>>()?;
+                    Some((direction, outgoing))
+                });
+                match route {
+                    Some((incoming, outgoing)) => optic = optic.route(incoming, &outgoing),
+                    None => {
+                        let kind = ErrorKind::Malformed("`teleport` or an `in:out` arrow route");
+                        return Err(line.error(kind, part));
+                    }
+                }
+            }
+            legend.insert(optic);
+        }
+        Ok(legend)
+    }
+}
@@ -1,3 +0,0 @@
-fn main() {
-    day16a::run()
-}
@@ -0,0 +1,3 @@
+fn main() {
+    day17::run()
+}
@@ -0,0 +1,418 @@
+use aoc_core::{
+    exit_with_error, get_neighbour, input_source_from_args, parse_grid, Direction, Error,
+    ErrorKind, Grid, Location, Solution,
+};
+use std::fmt::Display;
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+mod render;
+
+pub use render::{render, Format};
+
+type ReturnType = u128;
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<u32>,
+}
+
+impl Input {
+    fn get(&self, location: &Location) -> ReturnType {
+        self.grid[location.y][location.x] as ReturnType
+    }
+}
+
+// Heat loss of a single block, only used while parsing.
+struct Digit(u32);
+
+impl TryFrom<char> for Digit {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        value.to_digit(10).map(Digit).ok_or(Error::invalid_tile(value))
+    }
+}
+
+impl TryFrom<&str> for Input {
+    type Error = Error;
+
+    fn try_from(input_str: &str) -> Result<Self, Self::Error> {
+        let grid = parse_grid::<Digit>(input_str)?
+            .into_iter()
+            .map(|row| row.into_iter().map(|digit| digit.0).collect())
+            .collect();
+        Ok(Input { grid })
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Grid")?;
+        for line in &self.grid {
+            for tile in line {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)
+    }
+}
+
+// Rules for how far a crucible moves in a straight line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    // Blocks to move in a direction before it may turn.
+    min_run: u32,
+    // Blocks it may move in a direction before it has to turn.
+    max_run: u32,
+    // Whether it may stop at the end before completing min_run.
+    stop_mid_run: bool,
+}
+
+impl Crucible {
+    pub const NORMAL: Crucible = Crucible {
+        min_run: 1,
+        max_run: 3,
+        stop_mid_run: true,
+    };
+
+    pub const ULTRA: Crucible = Crucible {
+        min_run: 4,
+        max_run: 10,
+        stop_mid_run: false,
+    };
+
+    // A turn starts a new run of one block, so max_run must be at least 1 and min_run.
+    pub fn new(min_run: u32, max_run: u32, stop_mid_run: bool) -> Result<Crucible, Error> {
+        if max_run == 0 || min_run > max_run {
+            return Err(Error::new(
+                ErrorKind::Malformed("runs with 1 <= max_run and min_run <= max_run"),
+                format!("min_run {}, max_run {}", min_run, max_run),
+            ));
+        }
+        Ok(Crucible {
+            min_run,
+            max_run,
+            stop_mid_run,
+        })
+    }
+
+    fn can_turn(&self, state: &State) -> bool {
+        state.run >= self.min_run
+    }
+
+    fn can_go_straight(&self, state: &State) -> bool {
+        state.run < self.max_run
+    }
+
+    fn can_stop(&self, state: &State) -> bool {
+        self.stop_mid_run || state.run >= self.min_run
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+struct State {
+    location: Location,
+    direction: Direction,
+    // Blocks moved in direction so far, including the one at location.
+    run: u32,
+}
+
+// Heap entry, ordered so the BinaryHeap pops the smallest heat loss first.
+#[derive(Debug, Eq, PartialEq)]
+struct Candidate {
+    heat_loss: ReturnType,
+    state: State,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.heat_loss.cmp(&self.heat_loss)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn get_next_states(input: &Input, crucible: &Crucible, state: &State) -> Vec<State> {
+    Direction::ALL
+        .into_iter()
+        .filter(|direction| *direction != state.direction.opposite())
+        .filter(|direction| {
+            if *direction == state.direction {
+                crucible.can_go_straight(state)
+            } else {
+                crucible.can_turn(state)
+            }
+        })
+        .filter_map(|direction| {
+            let location = get_neighbour(&input.grid, &state.location, direction)?;
+            let run = if direction == state.direction {
+                state.run + 1
+            } else {
+                1
+            };
+            Some(State {
+                location,
+                direction,
+                run,
+            })
+        })
+        .collect()
+}
+
+// One block of a route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub location: Location,
+    // Direction moved to enter location.
+    pub direction: Direction,
+    // Heat loss from the start up to and including this block.
+    pub heat_loss: ReturnType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub start: Location,
+    pub steps: Vec<Step>,
+}
+
+impl Route {
+    pub fn heat_loss(&self) -> ReturnType {
+        self.steps.last().map_or(0, |step| step.heat_loss)
+    }
+
+    pub fn locations(&self) -> Vec<Location> {
+        std::iter::once(self.start)
+            .chain(self.steps.iter().map(|step| step.location))
+            .collect()
+    }
+}
+
+// Dijkstra over (location, direction, run) states, the heat loss of the start block is not counted.
+pub fn find_route(
+    input: &Input,
+    crucible: &Crucible,
+    start: Location,
+    end: Location,
+) -> Option<Route> {
+    // Lowest heat loss found so far for every state, indexed by state_index.
+    let width = input.grid[0].len();
+    let runs = crucible.max_run as usize + 1;
+    let state_index = |state: &State| {
+        let direction = Direction::ALL
+            .iter()
+            .position(|d| *d == state.direction)
+            .unwrap_or(0);
+        ((state.location.y * width + state.location.x) * 4 + direction) * runs
+            + state.run as usize
+    };
+    let mut best: Vec<Option<ReturnType>> = vec![None; input.grid.len() * width * 4 * runs];
+    // State each state was reached from, None for the start.
+    let mut came_from: Vec<Option<State>> = vec![None; best.len()];
+    let mut heap = BinaryHeap::new();
+    // The crucible has not moved yet, it may set off in either direction.
+    for direction in [Direction::Right, Direction::Down] {
+        let state = State {
+            location: start,
+            direction,
+            run: 0,
+        };
+        best[state_index(&state)] = Some(0);
+        heap.push(Candidate {
+            heat_loss: 0,
+            state,
+        });
+    }
+    while let Some(Candidate { heat_loss, state }) = heap.pop() {
+        if state.location == end && crucible.can_stop(&state) {
+            let mut steps = Vec::new();
+            let mut current = state;
+            while let Some(previous) = came_from[state_index(&current)] {
+                steps.push(Step {
+                    location: current.location,
+                    direction: current.direction,
+                    heat_loss: best[state_index(&current)].unwrap_or(0),
+                });
+                current = previous;
+            }
+            steps.reverse();
+            return Some(Route { start, steps });
+        }
+        if best[state_index(&state)].is_some_and(|known| known < heat_loss) {
+            continue;
+        }
+        for next in get_next_states(input, crucible, &state) {
+            let next_heat_loss = heat_loss + input.get(&next.location);
+            let next_index = state_index(&next);
+            if best[next_index].is_none_or(|known| next_heat_loss < known) {
+                best[next_index] = Some(next_heat_loss);
+                came_from[next_index] = Some(state);
+                heap.push(Candidate {
+                    heat_loss: next_heat_loss,
+                    state: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+// Best route from the top left to the bottom right block.
+pub fn find_best_route(input: &Input, crucible: &Crucible) -> Result<Route, Error> {
+    let start = Location { x: 0, y: 0 };
+    let end = Location {
+        x: input.grid[0].len() - 1,
+        y: input.grid.len() - 1,
+    };
+    find_route(input, crucible, start, end)
+        .ok_or_else(|| Error::no_solution("the end cannot be reached"))
+}
+
+fn calculate(input: &Input, crucible: &Crucible) -> Result<ReturnType, Error> {
+    Ok(find_best_route(input, crucible)?.heat_loss())
+}
+
+fn calculate_part_one(input: &Input) -> Result<ReturnType, Error> {
+    calculate(input, &Crucible::NORMAL)
+}
+
+fn calculate_part_two(input: &Input) -> Result<ReturnType, Error> {
+    calculate(input, &Crucible::ULTRA)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Input;
+
+    fn parse(input_str: &str) -> Result<Self::Input, Error> {
+        Input::try_from(input_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_one(input)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Error> {
+        calculate_part_two(input)
+    }
+}
+
+pub const INPUT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt");
+
+const USAGE: &str = "Usage: day17 [PATH|-] [--route plain|ansi|svg] [--ultra]";
+
+// With `--route` the best route of the normal or the ultra crucible is printed instead of the answers.
+pub fn run() {
+    let mut format = None;
+    let mut crucible = Crucible::NORMAL;
+    let source = input_source_from_args(Day17::DAY, INPUT_PATH, USAGE, |flag, args| {
+        match flag {
+            "--route" => format = Some(args.next().ok_or(USAGE)?.parse()?),
+            "--ultra" => crucible = Crucible::ULTRA,
+            _ => return Err(USAGE.to_string()),
+        }
+        Ok(())
+    })
+    .unwrap_or_else(|err| exit_with_error(&err));
+    let input_str = source.read().unwrap_or_else(|err| exit_with_error(&err));
+    let fail = |err: Error| -> ! { exit_with_error(&err.in_day(Day17::DAY).to_string()) };
+    let input = Input::try_from(input_str.as_str()).unwrap_or_else(|err| fail(err));
+    match format {
+        Some(format) => {
+            let route = find_best_route(&input, &crucible).unwrap_or_else(|err| fail(err));
+            print!("{}", render(&input, &route, format));
+        }
+        None => {
+            let part_one = calculate_part_one(&input).unwrap_or_else(|err| fail(err));
+            println!("Result for day17 part one: {}", part_one);
+            let part_two = calculate_part_two(&input).unwrap_or_else(|err| fail(err));
+            println!("Result for day17 part two: {}", part_two);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INPUT: &str = "
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    #[test]
+    fn part_one_works() {
+        let result = calculate_part_one(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(102));
+    }
+
+    #[test]
+    fn part_one_limits_straight_runs() {
+        let sample_input = "
+11111
+99991
+";
+        // Going right along the top row is cheapest, but after three blocks the crucible has to turn.
+        let result = calculate_part_one(&Input::try_from(sample_input).unwrap());
+        assert_eq!(result, Ok(13));
+    }
+
+    #[test]
+    fn part_two_works() {
+        let result = calculate_part_two(&Input::try_from(SAMPLE_INPUT).unwrap());
+        assert_eq!(result, Ok(94));
+    }
+
+    #[test]
+    fn ultra_crucible_cannot_stop_mid_run() {
+        let sample_input = "
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+        let input = Input::try_from(sample_input).unwrap();
+        assert_eq!(calculate_part_two(&input), Ok(71));
+        let stopping = Crucible::new(4, 10, true).unwrap();
+        assert_eq!(calculate(&input, &stopping), Ok(47));
+        assert!(Crucible::new(0, 0, true).is_err());
+        assert!(Crucible::new(4, 3, false).is_err());
+    }
+
+    #[test]
+    fn best_route_is_returned_as_data() {
+        use Direction::{Down, Right};
+        let input = Input::try_from("11111\n99991\n").unwrap();
+        let route = find_best_route(&input, &Crucible::NORMAL).unwrap();
+        let directions: Vec<Direction> = route.steps.iter().map(|step| step.direction).collect();
+        assert_eq!(directions, vec![Right, Right, Right, Down, Right]);
+        let heat_losses: Vec<ReturnType> = route.steps.iter().map(|step| step.heat_loss).collect();
+        assert_eq!(heat_losses, vec![1, 2, 3, 12, 13]);
+        assert_eq!(route.locations()[4], Location { x: 3, y: 1 });
+        assert_eq!(
+            render(&input, &route, Format::Plain),
+            ">>>v1\n999>*\nHeat loss: 13\n"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::str::FromStr;
+
+use aoc_core::Location;
+
+use crate::{Input, Route};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Plain,
+    Ansi,
+    Svg,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "plain" => Ok(Format::Plain),
+            "ansi" => Ok(Format::Ansi),
+            "svg" => Ok(Format::Svg),
+            other => Err(format!("Unknown route format: {}", other)),
+        }
+    }
+}
+
+pub fn render(input: &Input, route: &Route, format: Format) -> String {
+    match format {
+        Format::Plain => render_text(input, route, false),
+        Format::Ansi => render_text(input, route, true),
+        Format::Svg => render_svg(input, route),
+    }
+}
+
+// Symbol for each block of the route: the direction the crucible leaves it in, `*` for the end.
+fn route_symbols(route: &Route) -> HashMap<Location, String> {
+    let leaving = route.steps.iter().map(|step| step.direction.to_string());
+    route
+        .locations()
+        .into_iter()
+        .zip(leaving.chain(std::iter::once("*".to_string())))
+        .collect()
+}
+
+fn render_text(input: &Input, route: &Route, colored: bool) -> String {
+    let symbols = route_symbols(route);
+    let mut result = String::new();
+    for (y, row) in input.grid.iter().enumerate() {
+        for (x, heat_loss) in row.iter().enumerate() {
+            match (symbols.get(&Location { x, y }), colored) {
+                (Some(symbol), true) => result += &format!("\x1b[1;31m{}\x1b[0m", symbol),
+                (Some(symbol), false) => result += symbol,
+                (None, true) => result += &format!("\x1b[2m{}\x1b[0m", heat_loss),
+                (None, false) => result += &heat_loss.to_string(),
+            }
+        }
+        result += "\n";
+    }
+    writeln!(result, "Heat loss: {}", route.heat_loss()).unwrap();
+    result
+}
+
+const CELL: usize = 20;
+
+fn render_svg(input: &Input, route: &Route) -> String {
+    let height = input.grid.len();
+    let width = input.grid[0].len();
+    let mut result = String::new();
+    writeln!(
+        result,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="7">"#,
+        width * CELL,
+        height * CELL
+    )
+    .unwrap();
+    for (y, row) in input.grid.iter().enumerate() {
+        for (x, heat_loss) in row.iter().enumerate() {
+            // Darker blocks lose more heat.
+            let shade = 255 - heat_loss * 20;
+            writeln!(
+                result,
+                r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="rgb({shade},{shade},{shade})"/>"#,
+                x * CELL,
+                y * CELL
+            )
+            .unwrap();
+        }
+    }
+    let points = route
+        .locations()
+        .iter()
+        .map(|l| format!("{},{}", l.x * CELL + CELL / 2, l.y * CELL + CELL / 2))
+        .collect::<Vec<String>>()
+        .join(" ");
+    writeln!(
+        result,
+        r#"<polyline points="{}" fill="none" stroke="red" stroke-width="3"/>"#,
+        points
+    )
+    .unwrap();
+    // Cumulative heat loss on every block of the route.
+    for step in &route.steps {
+        writeln!(
+            result,
+            r#"<text x="{}" y="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+            step.location.x * CELL + CELL / 2,
+            step.location.y * CELL + CELL / 2,
+            step.heat_loss
+        )
+        .unwrap();
+    }
+    writeln!(result, "</svg>").unwrap();
+    result
+}